@@ -0,0 +1,31 @@
+use damped_springs::prelude::*;
+
+const DELTA: f64 = 0.1;
+const ITERATIONS: i32 = 64;
+
+fn main() {
+    // `Spring::update` solves the spring's motion exactly, while numerical integrators
+    // only approximate it and drift further from the true motion over time.
+
+    let config = SpringConfig::new(5.0, 0.2);
+    let time_step = SpringTimeStep::new(config, DELTA);
+
+    let mut exact = Spring::from_equilibrium(1.0);
+    let mut euler = exact;
+    let mut rk2 = exact;
+
+    println!("time\texact\teuler\trk2");
+    for i in 1..=ITERATIONS {
+        exact.update(time_step);
+        euler.euler_update(config, DELTA);
+        rk2.rk2_update(config, DELTA);
+
+        println!(
+            "{:.1}\t{:.4}\t{:.4}\t{:.4}",
+            i as f64 * DELTA,
+            exact.position,
+            euler.position,
+            rk2.position
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use damped_springs::prelude::*;
+
+const PHYSICS_DELTA: f64 = 1.0 / 20.0;
+const RENDER_DELTA: f64 = 1.0 / 60.0;
+const FRAMES: i32 = 48;
+
+fn main() {
+    // Springs are often simulated on a fixed time step while frames are rendered at a
+    // different (possibly varying) rate. Interpolating between the last two simulated
+    // states keeps rendered motion smooth.
+
+    let config = SpringConfig::new(5.0, 0.5);
+    let time_step = SpringTimeStep::new(config, PHYSICS_DELTA);
+
+    let mut previous = Spring::from_equilibrium(1.0);
+    let mut current = previous;
+    let mut accumulator = 0.0;
+
+    for _ in 0..FRAMES {
+        accumulator += RENDER_DELTA;
+        while accumulator >= PHYSICS_DELTA {
+            previous = current;
+            current.update(time_step);
+            accumulator -= PHYSICS_DELTA;
+        }
+
+        let alpha = accumulator / PHYSICS_DELTA;
+        let render = Spring::interpolate_render_state(&previous, &current, alpha);
+        println!("boing! {}", render.position);
+    }
+}
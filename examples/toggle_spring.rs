@@ -0,0 +1,23 @@
+use damped_springs::prelude::*;
+
+const ITERATIONS: i32 = 16;
+
+fn main() {
+    // A toggle switch whose knob springs between -1 (off) and 1 (on).
+
+    let config = SpringConfig::new(12.0, 0.6);
+    let time_step = SpringTimeStep::new(config, 1.0 / 30.0);
+
+    let mut knob = Spring::from_equilibrium_at_position(-1.0, -1.0);
+
+    for _ in 0..3 {
+        // flip the switch
+        knob.mirror_equilibrium();
+        println!("toggled to {}", knob.equilibrium);
+
+        for _ in 0..ITERATIONS {
+            knob.update(time_step);
+            println!("boing! {}", knob.position);
+        }
+    }
+}
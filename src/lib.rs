@@ -26,19 +26,43 @@
      distribution.
 ******************************************************************************/
 
-use num_traits::Float;
+use num_traits::{Float, FloatConst};
+use std::fmt;
+use std::ops::{Add, Mul, MulAssign, Neg, Sub};
+
+#[cfg(feature = "bytemuck")]
+mod pod;
+#[cfg(feature = "bevy_reflect")]
+mod reflect;
+#[cfg(feature = "bevy_reflect")]
+pub use reflect::register_spring_types;
 
 pub mod prelude {
-    pub use crate::{Spring, SpringCollection, SpringConfig, SpringParams, SpringTimeStep};
+    pub use crate::{
+        spring_update, update_spring_array, update_springs_with_equilibriums, Spring,
+        SpringCollection, SpringConfig, SpringInterpolator, SpringParams, SpringTimeStep,
+        SpringWithHistory,
+    };
 }
 
 /// Configuration options for a spring. Composed of its `angular_freq` and `damping_ratio`.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 pub struct SpringConfig<F> {
     angular_freq: F,
     damping_ratio: F,
 }
 
+/// A critically damped spring with an angular frequency of `10` rad/s (about 1.6 Hz).
+///
+/// It settles to within 1% of its initial displacement in roughly two thirds of a second
+/// without overshooting, which suits most UI animations.
+impl<F: Float> Default for SpringConfig<F> {
+    fn default() -> Self {
+        Self::new(F::from(10.0).unwrap(), F::one())
+    }
+}
+
 impl<F: Float> SpringConfig<F> {
     /// Construct a new spring configuration. Constrains spring parameters to non-negative values.
     pub fn new(angular_freq: F, damping_ratio: F) -> Self {
@@ -59,12 +83,199 @@ impl<F: Float> SpringConfig<F> {
     pub fn damping_ratio(&self) -> F {
         self.damping_ratio
     }
+
+    /// Construct a spring configuration from the two real poles of its characteristic equation.
+    ///
+    /// Returns `None` unless both poles are negative, i.e. the spring is stable.
+    pub fn from_real_poles(pole1: F, pole2: F) -> Option<Self> {
+        if pole1 >= F::zero() || pole2 >= F::zero() {
+            return None;
+        }
+
+        let angular_freq = (pole1 * pole2).sqrt();
+        let damping_ratio = -(pole1 + pole2) / ((F::one() + F::one()) * angular_freq);
+
+        Some(Self::new(angular_freq, damping_ratio))
+    }
+
+    /// Construct a spring configuration from one of the complex conjugate poles
+    /// `real ± imag * i` of its characteristic equation.
+    ///
+    /// Returns `None` unless the real part is negative, i.e. the spring is stable.
+    pub fn from_complex_pole(real: F, imag: F) -> Option<Self> {
+        if real >= F::zero() {
+            return None;
+        }
+
+        let angular_freq = real.hypot(imag);
+        let damping_ratio = -real / angular_freq;
+
+        Some(Self::new(angular_freq, damping_ratio))
+    }
+
+    /// Returns `true` if the angular frequency and damping ratio of this config are finite.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.angular_freq.is_finite() && self.damping_ratio.is_finite()
+    }
+
+    /// The physical spring constant `k = angular_freq² * mass` (force per unit displacement)
+    /// of a spring with this config attached to the given `mass`.
+    #[inline]
+    pub fn spring_constant(&self, mass: F) -> F {
+        self.angular_freq * self.angular_freq * mass
+    }
+
+    /// The physical damping coefficient `c = 2 * damping_ratio * angular_freq * mass`
+    /// of a spring with this config attached to the given `mass`.
+    #[inline]
+    pub fn damping_coefficient(&self, mass: F) -> F {
+        (F::one() + F::one()) * self.damping_ratio * self.angular_freq * mass
+    }
+
+    /// Construct a new spring configuration from an angular frequency and a
+    /// [quality factor](SpringConfig::quality_factor).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `q` is not positive, since no damping ratio has a quality factor of zero or
+    /// below.
+    #[inline]
+    pub fn from_quality_factor(angular_freq: F, q: F) -> Self {
+        assert!(q > F::zero(), "a spring's quality factor must be positive");
+
+        Self::new(angular_freq, F::one() / ((F::one() + F::one()) * q))
+    }
+
+    /// The quality factor `Q = 1 / (2 * damping_ratio)` of this spring config, describing
+    /// how much energy it retains per oscillation. Springs with `Q > 1` are sharply resonant.
+    ///
+    /// Returns infinity for an undamped spring.
+    #[inline]
+    pub fn quality_factor(&self) -> F {
+        F::one() / ((F::one() + F::one()) * self.damping_ratio)
+    }
+
+    /// The damped angular frequency `angular_freq * sqrt(1 - damping_ratio²)` of this spring
+    /// config. Returns `None` unless the spring is under-damped.
+    #[inline]
+    pub fn damped_frequency(&self) -> Option<F> {
+        SpringParams::from(*self).damped_frequency()
+    }
+
+    /// Construct a critically damped spring configuration that settles (to within 1% of its
+    /// initial displacement, starting from rest) in `settling_frames` frames at `fps`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fps` or `settling_frames` is zero.
+    #[inline]
+    pub fn for_fps(fps: u32, settling_frames: u32) -> Self {
+        assert!(
+            fps > 0,
+            "a spring cannot be configured for zero frames per second"
+        );
+
+        Self::for_frame_count(settling_frames, F::one() / F::from(fps).unwrap())
+    }
+
+    /// Construct a critically damped spring configuration that settles (to within 1% of its
+    /// initial displacement, starting from rest) in `frame_count` frames of `delta` seconds.
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let config = SpringConfig::for_frame_count(30, 1.0 / 60.0);
+    /// let time_step = SpringTimeStep::new(config, 1.0 / 60.0);
+    ///
+    /// let mut spring = Spring::from_equilibrium_with_offset(0.0_f64, 1.0);
+    /// for _ in 0..30 {
+    ///     spring.update(time_step);
+    /// }
+    /// assert!(spring.position.abs() <= 0.01 + 1e-9);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frame_count` is zero, since no spring settles instantly.
+    pub fn for_frame_count(frame_count: u32, delta: F) -> Self {
+        assert!(frame_count > 0, "a spring cannot settle in zero frames");
+
+        // a critically damped spring released from rest is at `(1 + wt) * exp(-wt)` of its
+        // initial displacement, which reaches 1% at `wt ~= 6.638`
+        let settling_time = F::from(frame_count).unwrap() * delta;
+        let angular_freq = F::from(6.638352067993813).unwrap() / settling_time;
+
+        Self::new(angular_freq, F::one())
+    }
+
+    /// Construct a spring configuration with the given angular frequency, choosing the damping
+    /// ratio so that its amplitude decays to `settling_fraction` of its initial value within
+    /// `settling_time`.
+    ///
+    /// Solves `exp(-damping_ratio * angular_freq * settling_time) = settling_fraction` for the
+    /// damping ratio. This follows the amplitude envelope of under-damped and critically damped
+    /// springs, so it is an approximation, and does not hold for results above `1`.
+    pub fn from_angular_freq_and_settling(
+        angular_freq: F,
+        settling_fraction: F,
+        settling_time: F,
+    ) -> Self {
+        let damping_ratio = -settling_fraction.ln() / (angular_freq * settling_time);
+        Self::new(angular_freq, damping_ratio)
+    }
+
+    /// Returns this config as an `[angular_freq, damping_ratio]` array.
+    #[inline]
+    pub fn to_array(&self) -> [F; 2] {
+        [self.angular_freq, self.damping_ratio]
+    }
+
+    /// Construct a spring configuration from an `[angular_freq, damping_ratio]` array.
+    /// Like [`SpringConfig::new`], constrains both to non-negative values.
+    #[inline]
+    pub fn from_array([angular_freq, damping_ratio]: [F; 2]) -> Self {
+        Self::new(angular_freq, damping_ratio)
+    }
+
+    /// An opinionated spring configuration for character movement, controlled by a single
+    /// `responsiveness` in `[0, 1]` (clamped).
+    ///
+    /// Interpolates linearly from a sluggish, critically damped spring at `0`
+    /// (`angular_freq = 4`, `damping_ratio = 1`) to a snappy, slightly under-damped one at `1`
+    /// (`angular_freq = 30`, `damping_ratio = 0.8`).
+    pub fn for_character(responsiveness: F) -> Self {
+        let r = responsiveness.max(F::zero()).min(F::one());
+        let angular_freq = F::from(4.0).unwrap() + F::from(26.0).unwrap() * r;
+        let damping_ratio = F::one() - F::from(0.2).unwrap() * r;
+
+        Self::new(angular_freq, damping_ratio)
+    }
+
+    /// An opinionated spring configuration for cameras, controlled by a single
+    /// `responsiveness` in `[0, 1]` (clamped).
+    ///
+    /// Cameras should never overshoot, so this is always critically damped, with an angular
+    /// frequency interpolated linearly from `2` at `0` to `15` at `1`.
+    pub fn for_camera(responsiveness: F) -> Self {
+        let r = responsiveness.max(F::zero()).min(F::one());
+        let angular_freq = F::from(2.0).unwrap() + F::from(13.0).unwrap() * r;
+
+        Self::new(angular_freq, F::one())
+    }
+}
+
+/// Converts a config into an `(angular_freq, damping_ratio)` tuple.
+impl<F: Float> From<SpringConfig<F>> for (F, F) {
+    fn from(config: SpringConfig<F>) -> Self {
+        (config.angular_freq, config.damping_ratio)
+    }
 }
 
 /// Cached coefficients for a spring, based on its angular frequency and damping ratio.
 ///
 /// Do not construct directly; instead, use [`SpringParams::from`] with a [`SpringConfig`].
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 pub enum SpringParams<F> {
     /// The spring has no angular frequency and will not move.
     Static,
@@ -108,6 +319,130 @@ impl<F: Float> From<SpringConfig<F>> for SpringParams<F> {
     }
 }
 
+impl<F: Float> SpringParams<F> {
+    /// The characteristic time of this spring, i.e. the time it takes for its amplitude to
+    /// decay to `1/e` of its current value. Returns `None` if the spring is [`SpringParams::Static`].
+    ///
+    /// Over-damped springs use their dominant (slowest) pole.
+    pub fn characteristic_time(&self) -> Option<F> {
+        match *self {
+            Self::Static => None,
+            Self::OverDamped { z2, .. } => Some(F::one() / z2.abs()),
+            Self::CriticallyDamped { angular_freq } => Some(F::one() / angular_freq),
+            Self::UnderDamped { oz, .. } => Some(F::one() / oz),
+        }
+    }
+
+    /// The time it takes for this spring's amplitude to decay by half.
+    /// Returns `None` if the spring is [`SpringParams::Static`].
+    #[inline]
+    pub fn half_life(&self) -> Option<F> {
+        self.characteristic_time()
+            .map(|time| time * (F::one() + F::one()).ln())
+    }
+
+    /// The factor `exp(-dt / characteristic_time)` by which this spring's amplitude envelope
+    /// decays over `dt` seconds. Returns `1` if the spring is [`SpringParams::Static`].
+    ///
+    /// **Note:** this is not a bound on the displacement remaining. Over-damped and critically
+    /// damped springs decay more slowly than their dominant pole alone, e.g. a critically damped
+    /// spring released from rest keeps `(1 + angular_freq * dt) * exp(-angular_freq * dt)` of
+    /// its displacement.
+    #[inline]
+    pub fn decay_factor(&self, dt: F) -> F {
+        self.characteristic_time()
+            .map_or(F::one(), |time| (-dt / time).exp())
+    }
+
+    /// Returns these params for a spring with its angular frequency scaled by `scale`,
+    /// preserving its damping ratio. Returns [`SpringParams::Static`] if `scale` is not positive.
+    pub fn scale_frequency(&self, scale: F) -> Self {
+        if scale < F::epsilon() {
+            return Self::Static;
+        }
+
+        match *self {
+            Self::Static => Self::Static,
+            Self::OverDamped { zb, z1, z2 } => Self::OverDamped {
+                zb: zb * scale,
+                z1: z1 * scale,
+                z2: z2 * scale,
+            },
+            Self::CriticallyDamped { angular_freq } => Self::CriticallyDamped {
+                angular_freq: angular_freq * scale,
+            },
+            Self::UnderDamped { oz, a } => Self::UnderDamped {
+                oz: oz * scale,
+                a: a * scale,
+            },
+        }
+    }
+
+    /// The damped angular frequency of this spring. Returns `None` unless the spring is
+    /// [`SpringParams::UnderDamped`].
+    #[inline]
+    pub fn damped_frequency(&self) -> Option<F> {
+        match *self {
+            Self::UnderDamped { a, .. } => Some(a),
+            _ => None,
+        }
+    }
+
+    /// The period of this spring's damped oscillation, `2π / a`. Returns `None` unless the
+    /// spring is [`SpringParams::UnderDamped`].
+    ///
+    /// **Note:** this is the period of the damped oscillation, which is longer than the
+    /// period of the spring's natural (undamped) frequency.
+    #[inline]
+    pub fn oscillation_period(&self) -> Option<F>
+    where
+        F: FloatConst,
+    {
+        self.damped_frequency().map(|a| F::TAU() / a)
+    }
+
+    /// The frequency of this spring's damped oscillation in hertz. Returns `None` unless the
+    /// spring is [`SpringParams::UnderDamped`]. See [`SpringParams::oscillation_period`].
+    #[inline]
+    pub fn oscillation_frequency_hz(&self) -> Option<F>
+    where
+        F: FloatConst,
+    {
+        self.damped_frequency().map(|a| a / F::TAU())
+    }
+
+    /// The acceleration of a spring with these params at the given displacement and velocity,
+    /// i.e. `-angular_freq² * displacement - 2 * damping_ratio * angular_freq * velocity`.
+    fn acceleration(&self, displacement: F, velocity: F) -> F {
+        let (stiffness, damping) = self.stiffness_and_damping();
+        -stiffness * displacement - damping * velocity
+    }
+
+    /// The coefficients `(angular_freq², 2 * damping_ratio * angular_freq)` of this spring's
+    /// equation of motion. Both are zero for [`SpringParams::Static`].
+    fn stiffness_and_damping(&self) -> (F, F) {
+        match *self {
+            Self::Static => (F::zero(), F::zero()),
+            Self::OverDamped { z1, z2, .. } => (z1 * z2, -(z1 + z2)),
+            Self::CriticallyDamped { angular_freq } => (
+                angular_freq * angular_freq,
+                (F::one() + F::one()) * angular_freq,
+            ),
+            Self::UnderDamped { oz, a } => (oz * oz + a * a, (F::one() + F::one()) * oz),
+        }
+    }
+
+    /// The natural (undamped) angular frequency of this spring. Zero for [`SpringParams::Static`].
+    pub fn angular_freq(&self) -> F {
+        match *self {
+            Self::Static => F::zero(),
+            Self::OverDamped { z1, z2, .. } => (z1 * z2).sqrt(),
+            Self::CriticallyDamped { angular_freq } => angular_freq,
+            Self::UnderDamped { oz, a } => oz.hypot(a),
+        }
+    }
+}
+
 /// Cached coefficients for a spring, based on its configuration and a particular time step.
 ///
 /// Used to efficiently update one or more springs that share the same configuration.
@@ -117,11 +452,11 @@ impl<F: Float> From<SpringConfig<F>> for SpringParams<F> {
 /// To derive a `SpringTimeStep`, you must first get a [`SpringParams`] from a [`SpringConfig`]:
 ///
 /// ```
+/// # use damped_springs::prelude::*;
+/// # let mut spring = Spring::from_equilibrium(1.0);
+/// # let delta_time = 0.1;
 /// // configure your spring...
-/// let config = SpringConfig {
-///     angular_freq: 5,
-///     damping_ratio: 0.75,
-/// };
+/// let config = SpringConfig::new(5.0, 0.75);
 ///
 /// // derive its state
 /// let state = SpringParams::from(config);
@@ -133,7 +468,8 @@ impl<F: Float> From<SpringConfig<F>> for SpringParams<F> {
 /// // or let `Spring::update_single` do it for you
 /// spring.update_single(state, delta_time);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
+#[repr(C)]
 pub struct SpringTimeStep<F> {
     pp: F,
     pv: F,
@@ -141,6 +477,28 @@ pub struct SpringTimeStep<F> {
     vv: F,
 }
 
+/// Formats the time step as its state transition matrix `[[pp, pv], [vp, vv]]`, followed by
+/// a note on what its rows and columns represent.
+///
+/// ```
+/// # use damped_springs::prelude::*;
+/// assert_eq!(
+///     format!("{:?}", SpringTimeStep::<f64>::default()),
+///     "SpringTimeStep [[1.0, 0.0], [0.0, 1.0]] \
+///      (rows: new position/velocity; cols: displacement/velocity)",
+/// );
+/// ```
+impl<F: fmt::Debug> fmt::Debug for SpringTimeStep<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SpringTimeStep ")?;
+        f.debug_list()
+            .entry(&[&self.pp, &self.pv])
+            .entry(&[&self.vp, &self.vv])
+            .finish()?;
+        f.write_str(" (rows: new position/velocity; cols: displacement/velocity)")
+    }
+}
+
 impl<F: Float> Default for SpringTimeStep<F> {
     fn default() -> Self {
         Self {
@@ -154,6 +512,21 @@ impl<F: Float> Default for SpringTimeStep<F> {
 
 impl<F: Float> SpringTimeStep<F> {
     /// Derive a spring time step from a particular delta time.
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let (angular_freq, delta) = (4.0_f64, 0.25);
+    /// let (position, velocity) = (1.0, -2.0);
+    ///
+    /// let mut spring = Spring { position, velocity, equilibrium: 0.0 };
+    /// spring.update(SpringTimeStep::new(SpringConfig::new(angular_freq, 1.0), delta));
+    ///
+    /// // closed form of a critically damped spring
+    /// let c = velocity + angular_freq * position;
+    /// let exp = (-angular_freq * delta).exp();
+    /// assert!((spring.position - (position + c * delta) * exp).abs() < 1e-12);
+    /// assert!((spring.velocity - (velocity - angular_freq * c * delta) * exp).abs() < 1e-12);
+    /// ```
     pub fn new(state: impl Into<SpringParams<F>>, delta: F) -> Self {
         match state.into() {
             SpringParams::Static => Self::default(),
@@ -202,10 +575,10 @@ impl<F: Float> SpringTimeStep<F> {
                 let time_exp_freq = time_exp * angular_freq;
 
                 Self {
-                    pp: time_exp_freq * exp,
+                    pp: time_exp_freq + exp,
                     pv: time_exp,
                     vp: -angular_freq * time_exp_freq,
-                    vv: -time_exp_freq * exp,
+                    vv: -time_exp_freq + exp,
                 }
             }
         }
@@ -219,10 +592,91 @@ impl<F: Float> SpringTimeStep<F> {
             spring.update(self);
         }
     }
+
+    /// Derive a spring time step for `original_delta` played back at a time `scale`, e.g.
+    /// `0.5` for slow motion or `2` for fast forward.
+    ///
+    /// **Note:** an existing time step cannot be rescaled, since its coefficients are
+    /// exponential in the delta time and the delta time itself is not retained. Instead,
+    /// this constructs a fresh time step for `original_delta * scale`.
+    #[inline]
+    pub fn with_time_scale(state: impl Into<SpringParams<F>>, original_delta: F, scale: F) -> Self {
+        Self::new(state, original_delta * scale)
+    }
+
+    /// Returns `true` if all coefficients of this time step are finite.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.pp.is_finite() && self.pv.is_finite() && self.vp.is_finite() && self.vv.is_finite()
+    }
+
+    /// Update a fixed-size array of springs using this time step.
+    #[inline]
+    pub fn update_array<const N: usize>(self, springs: &mut [Spring<F>; N]) {
+        Spring::update_slice(springs, self);
+    }
+}
+
+/// Update a fixed-size array of springs using a pre-computed [`SpringTimeStep`].
+///
+/// This is the lowest-overhead way to update many springs at once, requiring neither a
+/// [`SpringCollection`] nor any allocation.
+#[inline]
+pub fn update_spring_array<F: Float, const N: usize>(
+    springs: &mut [Spring<F>; N],
+    time_step: SpringTimeStep<F>,
+) {
+    time_step.update_array(springs);
+}
+
+/// Set each spring's equilibrium to the corresponding value in `equilibriums`, then update
+/// them all using a pre-computed [`SpringTimeStep`].
+///
+/// # Panics
+///
+/// Panics if `springs` and `equilibriums` have different lengths.
+pub fn update_springs_with_equilibriums<F: Float>(
+    springs: &mut [Spring<F>],
+    equilibriums: &[F],
+    time_step: SpringTimeStep<F>,
+) {
+    assert_eq!(
+        springs.len(),
+        equilibriums.len(),
+        "every spring needs exactly one equilibrium"
+    );
+
+    for (spring, &equilibrium) in springs.iter_mut().zip(equilibriums) {
+        spring.equilibrium = equilibrium;
+        spring.update(time_step);
+    }
+}
+
+/// Update any number of springs over the same delta, constructing a single [`SpringTimeStep`]
+/// for all of them.
+///
+/// Equivalent to [`SpringTimeStep::update_many`], but more convenient for named springs.
+///
+/// ```
+/// # use damped_springs::prelude::*;
+/// let params = SpringParams::from(SpringConfig::new(5.0, 0.5));
+/// let mut x = Spring::from_equilibrium(1.0);
+/// let mut y = Spring::from_equilibrium(2.0);
+///
+/// spring_update!(params, 0.1, x, y);
+/// ```
+#[macro_export]
+macro_rules! spring_update {
+    ( $params:expr , $delta:expr $( , $spring:expr )+ $(,)? ) => {{
+        let time_step = $crate::SpringTimeStep::new($params, $delta);
+        $( $spring.update(time_step); )+
+    }};
 }
 
 /// An instance of a spring and its current physical properties, like its position, velocity, and target equilibrium.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[repr(C)]
 pub struct Spring<F> {
     pub position: F,
     pub velocity: F,
@@ -254,7 +708,28 @@ impl<F: Float> Spring<F> {
         *velocity = op * time_step.vp + ov * time_step.vv;
     }
 
+    #[inline]
+    fn snap_internal(
+        position: &mut F,
+        velocity: &mut F,
+        equilibrium: F,
+        position_threshold: F,
+        velocity_threshold: F,
+    ) {
+        if (*position - equilibrium).abs() < position_threshold
+            && velocity.abs() < velocity_threshold
+        {
+            *position = equilibrium;
+            *velocity = F::zero();
+        }
+    }
+
     /// Create a new spring from a start equilibrium.
+    ///
+    /// **Note:** the spring starts at rest at position zero, *not* at its equilibrium, so it
+    /// springs toward `equilibrium` once updated. To start at a position with an equilibrium of
+    /// zero, use [`Spring::at_position`]; to choose both, use
+    /// [`Spring::from_target_and_current_position`].
     pub fn from_equilibrium(equilibrium: F) -> Self {
         Self {
             position: F::zero(),
@@ -263,6 +738,73 @@ impl<F: Float> Spring<F> {
         }
     }
 
+    /// Create a new spring at rest at `position`, with an equilibrium of zero.
+    ///
+    /// This is the counterpart to [`Spring::from_equilibrium`], which sets the equilibrium
+    /// and starts the spring at zero instead.
+    pub fn at_position(position: F) -> Self {
+        Self {
+            position,
+            velocity: F::zero(),
+            equilibrium: F::zero(),
+        }
+    }
+
+    /// Create a new spring from a start equilibrium, displaced from it by `offset`.
+    pub fn from_equilibrium_with_offset(equilibrium: F, offset: F) -> Self {
+        Self::from_equilibrium_at_position(equilibrium, equilibrium + offset)
+    }
+
+    /// Create a new spring from a start equilibrium and a start position.
+    pub fn from_equilibrium_at_position(equilibrium: F, position: F) -> Self {
+        Self {
+            position,
+            velocity: F::zero(),
+            equilibrium,
+        }
+    }
+
+    /// Create a spring at rest at `current`, heading toward `target`.
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let spring = Spring::from_target_and_current_position(10.0, 2.0);
+    /// assert_eq!(spring.equilibrium, 10.0);
+    /// assert_eq!(spring.position, 2.0);
+    /// assert_eq!(spring.velocity, 0.0);
+    /// ```
+    #[inline]
+    pub fn from_target_and_current_position(target: F, current: F) -> Self {
+        Self::from_equilibrium_at_position(target, current)
+    }
+
+    /// Returns this spring with its equilibrium set to `equilibrium`.
+    #[inline]
+    pub fn with_equilibrium(mut self, equilibrium: F) -> Self {
+        self.equilibrium = equilibrium;
+        self
+    }
+
+    /// Returns this spring with its position set to `position`.
+    #[inline]
+    pub fn with_position(mut self, position: F) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Returns this spring with its velocity set to `velocity`.
+    #[inline]
+    pub fn with_velocity(mut self, velocity: F) -> Self {
+        self.velocity = velocity;
+        self
+    }
+
+    /// The displacement of this spring from its equilibrium, i.e. `position - equilibrium`.
+    #[inline]
+    pub fn displacement(&self) -> F {
+        self.position - self.equilibrium
+    }
+
     /// Update this spring using a pre-computed [`SpringTimeStep`].
     pub fn update(&mut self, time_step: SpringTimeStep<F>) {
         Self::update_internal(
@@ -273,6 +815,17 @@ impl<F: Float> Spring<F> {
         );
     }
 
+    /// Update a slice of springs using a pre-computed [`SpringTimeStep`].
+    ///
+    /// Unlike [`SpringTimeStep::update_many`], this takes the springs by value in a
+    /// contiguous slice, which leaves the compiler free to vectorize the loop.
+    #[inline]
+    pub fn update_slice(springs: &mut [Spring<F>], time_step: SpringTimeStep<F>) {
+        for spring in springs {
+            spring.update(time_step);
+        }
+    }
+
     /// Update this spring (and this spring only) using a [`SpringParams`] and a delta time.
     /// Will internally create a [`SpringTimeStep`] for this call.
     ///
@@ -283,86 +836,1870 @@ impl<F: Float> Spring<F> {
     pub fn update_single(&mut self, state: SpringParams<F>, delta: F) {
         self.update(SpringTimeStep::new(state, delta));
     }
-}
-
-/// A fixed-size collection of springs that all share the same spring parameters.
-/// Useful for creating springs over multiple dimensions (i.e. 2D or 3D springs).
-#[derive(Debug, Clone, PartialEq)]
-pub struct SpringCollection<F, const N: usize> {
-    params: SpringParams<F>,
-    positions: [F; N],
-    velocities: [F; N],
-    equilibriums: [F; N],
-}
 
-impl<F: Float, const N: usize, T> From<T> for SpringCollection<F, N>
-where
-    T: Into<SpringParams<F>>,
-{
-    fn from(params: T) -> Self {
-        Self {
-            params: params.into(),
-            positions: [F::zero(); N],
-            velocities: [F::zero(); N],
-            equilibriums: [F::zero(); N],
-        }
+    /// The change in position between two snapshots of a spring, i.e. `current - previous`.
+    ///
+    /// Keeping track of the previous snapshot is left up to the caller:
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let params = SpringParams::from(SpringConfig::new(5.0, 0.5));
+    /// let mut spring = Spring::from_equilibrium(1.0);
+    ///
+    /// let previous = spring;
+    /// spring.update_single(params, 0.1);
+    ///
+    /// let delta = Spring::position_delta(spring, previous);
+    /// assert_eq!(delta, spring.position - previous.position);
+    /// ```
+    #[inline]
+    pub fn position_delta(current: Self, previous: Self) -> F {
+        current.position - previous.position
     }
-}
 
-impl<F: Float, const N: usize> SpringCollection<F, N> {
-    /// Construct `N` springs, all starting at a specified `equilibrium`.
-    pub fn from_equilibrium(params: impl Into<SpringParams<F>>, equilibrium: F) -> Self {
-        Self {
-            params: params.into(),
-            positions: [F::zero(); N],
-            velocities: [F::zero(); N],
-            equilibriums: [equilibrium; N],
-        }
+    /// The change in velocity between two snapshots of a spring, i.e. `current - previous`.
+    ///
+    /// See [`Spring::position_delta`].
+    #[inline]
+    pub fn velocity_delta(current: Self, previous: Self) -> F {
+        current.velocity - previous.velocity
     }
 
-    /// Construct `N` springs, each with a particular equilibrium.
-    pub fn from_equilibriums(params: impl Into<SpringParams<F>>, equilibriums: [F; N]) -> Self {
-        Self {
-            params: params.into(),
-            positions: [F::zero(); N],
-            velocities: [F::zero(); N],
-            equilibriums,
+    /// Zeroes this spring's velocity if its magnitude is below `velocity_threshold`.
+    pub fn snap_velocity_if_small(&mut self, velocity_threshold: F) {
+        if self.velocity.abs() < velocity_threshold {
+            self.velocity = F::zero();
         }
     }
 
-    /// Update all springs over the specified delta. Constructs a new [`SpringTimeStep`]
-    /// for this usage.
+    /// Snaps this spring to rest at its equilibrium if both its displacement and velocity
+    /// are below their respective thresholds.
+    ///
+    /// Springs never truly settle; use this to put springs that are close enough to rest
+    /// to "sleep", much like sleeping physics bodies.
     #[inline]
-    pub fn update(&mut self, delta: F) {
-        self.update_with(SpringTimeStep::new(self.params, delta));
+    pub fn snap_if_settled(&mut self, position_threshold: F, velocity_threshold: F) {
+        Self::snap_internal(
+            &mut self.position,
+            &mut self.velocity,
+            self.equilibrium,
+            position_threshold,
+            velocity_threshold,
+        );
     }
 
-    /// Update all springs using the specified `time_step`.
+    /// The amplitude envelope of an under-damped spring `t` seconds from now, based on
+    /// its current state. Returns `None` if `params` is not [`SpringParams::UnderDamped`].
     ///
-    /// **Note:** this time step need not be derived from [`SpringCollection::params`].
-    /// The implementation of this method uses all constants from `time_step`.
-    #[inline]
+    /// The envelope `A * exp(-oz * t)` bounds the spring's displacement from its
+    /// equilibrium, where `A` is the spring's current oscillation amplitude.
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let params = SpringParams::from(SpringConfig::new(5.0, 0.25));
+    /// let time_step = SpringTimeStep::new(params, 0.05);
+    /// let spring = Spring::from_equilibrium(1.0);
+    ///
+    /// let mut simulated = spring;
+    /// for i in 1..100 {
+    ///     simulated.update(time_step);
+    ///     let envelope = spring.underdamped_envelope(params, i as f64 * 0.05).unwrap();
+    ///     assert!((simulated.position - simulated.equilibrium).abs() <= envelope + 1e-9);
+    /// }
+    /// ```
+    pub fn underdamped_envelope(&self, params: SpringParams<F>, t: F) -> Option<F> {
+        match params {
+            SpringParams::UnderDamped { oz, .. } => self
+                .oscillation_amplitude(params)
+                .map(|amplitude| amplitude * (-oz * t).exp()),
+            _ => None,
+        }
+    }
+
+    /// The current amplitude of this under-damped spring's oscillation. Returns `None` if
+    /// `params` is not [`SpringParams::UnderDamped`].
+    ///
+    /// An under-damped spring moves as `x(t) = exp(-oz * t) * (d * cos(a * t) + s * sin(a * t))`,
+    /// where `d` is its displacement and `s = (velocity + oz * d) / a`. The amplitude is the
+    /// magnitude `sqrt(d² + s²)` of that phasor, which only ever decreases over time.
+    pub fn oscillation_amplitude(&self, params: SpringParams<F>) -> Option<F> {
+        match params {
+            SpringParams::UnderDamped { oz, a } => {
+                let displacement = self.displacement();
+                let sin_coeff = (self.velocity + oz * displacement) / a;
+                Some(displacement.hypot(sin_coeff))
+            }
+            _ => None,
+        }
+    }
+
+    /// The current phase angle, in radians, of this under-damped spring's oscillation.
+    /// Returns `None` if `params` is not [`SpringParams::UnderDamped`].
+    ///
+    /// Using the phasor from [`Spring::oscillation_amplitude`], the spring moves as
+    /// `x(t) = amplitude * exp(-oz * t) * cos(a * t + phase)`, so the phase advances at `a`
+    /// radians per second. Useful for keeping several springs' oscillations in sync.
+    pub fn oscillation_phase(&self, params: SpringParams<F>) -> Option<F> {
+        match params {
+            SpringParams::UnderDamped { oz, a } => {
+                let displacement = self.displacement();
+                let sin_coeff = (self.velocity + oz * displacement) / a;
+                Some((-sin_coeff).atan2(displacement))
+            }
+            _ => None,
+        }
+    }
+
+    /// Scale this spring's position and velocity by `factor`, leaving its equilibrium untouched.
+    ///
+    /// Unlike multiplying a spring by a scalar, this scales the spring's motion relative to
+    /// the origin without moving its target.
+    #[inline]
+    pub fn scale_dynamics(&mut self, factor: F) {
+        self.position = self.position * factor;
+        self.velocity = self.velocity * factor;
+    }
+
+    /// Estimate how long a spring configured with `config` takes to settle to within
+    /// `tolerance_fraction` of an `initial_displacement` (e.g. `0.01` for 1%), starting from rest.
+    ///
+    /// Over-damped and critically damped springs released from rest approach their equilibrium
+    /// monotonically, so their settling time is found exactly by bisection. Under-damped springs
+    /// use their exponential amplitude envelope, which gives an upper bound. Returns infinity if
+    /// the spring is [`SpringParams::Static`].
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let config = SpringConfig::new(10.0_f64, 1.0);
+    /// let settle_time = Spring::settle_time_for_config(config, 1.0, 0.01);
+    ///
+    /// let settled = Spring::from_equilibrium_with_offset(0.0, 1.0)
+    ///     .peek_stepped(SpringTimeStep::new(config, settle_time));
+    /// assert!((settled.position - 0.01).abs() < 1e-9);
+    /// ```
+    pub fn settle_time_for_config(
+        config: SpringConfig<F>,
+        initial_displacement: F,
+        tolerance_fraction: F,
+    ) -> F {
+        let params = SpringParams::from(config);
+
+        // the fraction of its displacement a non-oscillating spring released from rest has left
+        let fraction_at = |t: F| match params {
+            SpringParams::OverDamped { zb, z1, z2 } => {
+                // x(t) / x(0) = (z2 * exp(z1 * t) - z1 * exp(z2 * t)) / (z2 - z1)
+                (z2 * (z1 * t).exp() - z1 * (z2 * t).exp()) / ((F::one() + F::one()) * zb)
+            }
+            SpringParams::CriticallyDamped { angular_freq } => {
+                (F::one() + angular_freq * t) * (-angular_freq * t).exp()
+            }
+            _ => F::zero(),
+        };
+
+        match params {
+            SpringParams::Static => F::infinity(),
+            _ if initial_displacement == F::zero() || tolerance_fraction >= F::one() => F::zero(),
+            _ if tolerance_fraction <= F::zero() => F::infinity(),
+            SpringParams::UnderDamped { oz, a } => {
+                // amplitude of a spring released from rest, relative to its displacement
+                let amplitude = F::one().hypot(oz / a);
+                (amplitude / tolerance_fraction).ln() / oz
+            }
+            SpringParams::OverDamped { .. } | SpringParams::CriticallyDamped { .. } => {
+                // `fraction_at` decreases monotonically, so bisect it once it is bracketed
+                let mut end = params.characteristic_time().unwrap_or(F::one());
+                while fraction_at(end) > tolerance_fraction {
+                    end = end + end;
+                }
+                Self::bisect(|t| fraction_at(t) - tolerance_fraction, F::zero(), end)
+            }
+        }
+    }
+
+    /// Move this spring's equilibrium toward `new_equilibrium` by `blend_factor`, then update
+    /// it using a pre-computed [`SpringTimeStep`].
+    ///
+    /// A `blend_factor` of `1` sets the new equilibrium directly, while smaller values
+    /// converge toward it over several updates, avoiding abrupt jumps in the target.
+    pub fn update_with_equilibrium_blend(
+        &mut self,
+        time_step: SpringTimeStep<F>,
+        new_equilibrium: F,
+        blend_factor: F,
+    ) {
+        self.equilibrium = self.equilibrium + (new_equilibrium - self.equilibrium) * blend_factor;
+        self.update(time_step);
+    }
+
+    /// Returns a copy of this spring, updated using a pre-computed [`SpringTimeStep`].
+    #[inline]
+    pub fn clone_stepped(mut self, time_step: SpringTimeStep<F>) -> Self {
+        self.update(time_step);
+        self
+    }
+
+    /// Returns where this spring will be after a [`SpringTimeStep`], without updating it.
+    ///
+    /// Useful for predicting the next frame's state, e.g. for frame interpolation.
+    #[inline]
+    pub fn peek_stepped(&self, time_step: SpringTimeStep<F>) -> Self {
+        self.clone_stepped(time_step)
+    }
+
+    /// Update this spring using a pre-computed [`SpringTimeStep`], then clamp its velocity
+    /// to `[-max_speed, max_speed]`.
+    ///
+    /// **Note:** clamping the velocity removes energy from the spring, which may alter how
+    /// (and how quickly) it settles.
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let params = SpringParams::from(SpringConfig::new(100.0, 0.1));
+    /// let time_step = SpringTimeStep::new(params, 0.01);
+    ///
+    /// let mut spring = Spring::from_equilibrium(10.0_f64);
+    /// spring.velocity = 500.0;
+    /// for _ in 0..100 {
+    ///     spring.update_capped_velocity(time_step, 20.0);
+    ///     assert!(spring.velocity.abs() <= 20.0);
+    /// }
+    /// ```
+    #[inline]
+    pub fn update_capped_velocity(&mut self, time_step: SpringTimeStep<F>, max_speed: F) {
+        self.update_capped_velocity_returning_clipped(time_step, max_speed);
+    }
+
+    /// Like [`Spring::update_capped_velocity`], but returns `true` if the velocity was clamped.
+    pub fn update_capped_velocity_returning_clipped(
+        &mut self,
+        time_step: SpringTimeStep<F>,
+        max_speed: F,
+    ) -> bool {
+        self.update(time_step);
+
+        let clamped = self.velocity.max(-max_speed).min(max_speed);
+        let clipped = clamped != self.velocity;
+        self.velocity = clamped;
+        clipped
+    }
+
+    /// Returns `true` if this spring's position, velocity, and equilibrium are all finite.
+    ///
+    /// Useful for validating spring state received from elsewhere, e.g. after deserialization.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.position.is_finite() && self.velocity.is_finite() && self.equilibrium.is_finite()
+    }
+
+    /// Resets any non-finite (NaN or infinite) fields of this spring to zero.
+    pub fn sanitize(&mut self) {
+        for field in [
+            &mut self.position,
+            &mut self.velocity,
+            &mut self.equilibrium,
+        ] {
+            if !field.is_finite() {
+                *field = F::zero();
+            }
+        }
+    }
+
+    /// Interpolate between a `previous` and `current` spring state by `alpha`, keeping
+    /// `current`'s equilibrium.
+    ///
+    /// Intended for rendering when springs are simulated on a fixed time step but drawn at
+    /// a variable rate. The result should only be used for rendering and never be fed back
+    /// into the simulation.
+    pub fn interpolate_render_state(previous: &Self, current: &Self, alpha: F) -> Self {
+        Self {
+            position: previous.position + (current.position - previous.position) * alpha,
+            velocity: previous.velocity + (current.velocity - previous.velocity) * alpha,
+            equilibrium: current.equilibrium,
+        }
+    }
+
+    /// Like [`Spring::update_with_equilibrium_blend`], but returns the change in this
+    /// spring's position over the update.
+    pub fn update_then_clamp_equilibrium(
+        &mut self,
+        time_step: SpringTimeStep<F>,
+        new_equilibrium: F,
+        blend_factor: F,
+    ) -> F {
+        let previous = *self;
+        self.update_with_equilibrium_blend(time_step, new_equilibrium, blend_factor);
+        Self::position_delta(*self, previous)
+    }
+
+    /// Update this spring as if it were attached to a platform moving at `parent_velocity`,
+    /// where `delta` is the delta time `time_step` was derived from.
+    ///
+    /// The spring's position, velocity, and equilibrium are all in world space: the
+    /// equilibrium rides along with the platform, and the spring's velocity includes the
+    /// platform's. Call this in place of [`Spring::update`] every frame, passing the
+    /// platform's velocity for that frame.
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let params = SpringParams::from(SpringConfig::new(5.0, 0.5));
+    /// let time_step = SpringTimeStep::new(params, 0.05);
+    ///
+    /// let mut spring = Spring::from_equilibrium(1.0_f64);
+    /// for _ in 0..200 {
+    ///     spring.update_with_parent_velocity(time_step, 2.0, 0.05);
+    /// }
+    ///
+    /// assert!((spring.equilibrium - 21.0).abs() < 1e-9);
+    /// assert!((spring.position - spring.equilibrium).abs() < 1e-6);
+    /// assert!((spring.velocity - 2.0).abs() < 1e-6);
+    /// ```
+    pub fn update_with_parent_velocity(
+        &mut self,
+        time_step: SpringTimeStep<F>,
+        parent_velocity: F,
+        delta: F,
+    ) {
+        // simulate relative to the platform, then move along with it
+        self.velocity = self.velocity - parent_velocity;
+        self.update(time_step);
+
+        let offset = parent_velocity * delta;
+        self.position = self.position + offset;
+        self.equilibrium = self.equilibrium + offset;
+        self.velocity = self.velocity + parent_velocity;
+    }
+
+    /// Simulate two springs sharing `params` for `steps` updates of `delta`, returning their
+    /// `(a, b)` positions after each update.
+    ///
+    /// Intended as a debugging aid and for generating test data.
+    pub fn simulate_pair(
+        a: Self,
+        b: Self,
+        params: SpringParams<F>,
+        delta: F,
+        steps: usize,
+    ) -> Vec<(F, F)> {
+        Self::simulate_collection([a, b], params, delta, steps)
+            .into_iter()
+            .map(|[a, b]| (a, b))
+            .collect()
+    }
+
+    /// Simulate `N` springs sharing `params` for `steps` updates of `delta`, returning
+    /// their positions after each update.
+    ///
+    /// Intended as a debugging aid and for generating test data.
+    pub fn simulate_collection<const N: usize>(
+        mut springs: [Self; N],
+        params: SpringParams<F>,
+        delta: F,
+        steps: usize,
+    ) -> Vec<[F; N]> {
+        let time_step = SpringTimeStep::new(params, delta);
+        (0..steps)
+            .map(|_| {
+                Self::update_slice(&mut springs, time_step);
+                springs.map(|spring| spring.position)
+            })
+            .collect()
+    }
+
+    /// Update this spring (and this spring only) with its natural frequency scaled by
+    /// `freq_scale`, e.g. for slow-motion effects.
+    ///
+    /// Scaling the frequency preserves the spring's damping ratio, so it keeps its character
+    /// while moving faster or slower. See [`SpringParams::scale_frequency`].
+    #[inline]
+    pub fn update_frequency_scaled(&mut self, params: SpringParams<F>, delta: F, freq_scale: F) {
+        self.update_single(params.scale_frequency(freq_scale), delta);
+    }
+
+    /// Update this spring using a pre-computed [`SpringTimeStep`], returning its new
+    /// `(position, velocity)`.
+    #[inline]
+    pub fn update_returning(&mut self, time_step: SpringTimeStep<F>) -> (F, F) {
+        self.update(time_step);
+        (self.position, self.velocity)
+    }
+
+    /// Like [`Spring::update_single`], but returns the spring's new `(position, velocity)`.
+    #[inline]
+    pub fn update_single_returning(&mut self, state: SpringParams<F>, delta: F) -> (F, F) {
+        self.update_returning(SpringTimeStep::new(state, delta))
+    }
+
+    /// How much of this spring's motion remains, as a fraction of the energy it had when
+    /// displaced by `initial_displacement` from rest: `1` when just started, `0` when settled.
+    ///
+    /// Energy is measured as `displacement² + (velocity / angular_freq)²`, and the result is
+    /// clamped to `[0, 1]`. Useful for driving progress bars, blend weights, or fade-outs.
+    pub fn completion_fraction(&self, angular_freq: F, initial_displacement: F) -> F {
+        if initial_displacement == F::zero() {
+            return F::zero();
+        }
+
+        (self.scaled_energy(angular_freq) / (initial_displacement * initial_displacement))
+            .max(F::zero())
+            .min(F::one())
+    }
+
+    /// Like [`Spring::completion_fraction`], but returns the unclamped amplitude ratio
+    /// `sqrt(displacement² + (velocity / angular_freq)²) / |initial_displacement|`.
+    ///
+    /// This dimensionless value makes springs of different scales and configurations
+    /// comparable, e.g. so UI animations feel the same regardless of their size.
+    pub fn normalized_energy(&self, params: SpringParams<F>, initial_displacement: F) -> F {
+        if initial_displacement == F::zero() {
+            return F::zero();
+        }
+
+        self.scaled_energy(params.angular_freq()).sqrt() / initial_displacement.abs()
+    }
+
+    /// `displacement² + (velocity / angular_freq)²`, ignoring velocity if `angular_freq` is zero.
+    fn scaled_energy(&self, angular_freq: F) -> F {
+        let displacement = self.displacement();
+        let mut energy = displacement * displacement;
+        if angular_freq > F::epsilon() {
+            let scaled_velocity = self.velocity / angular_freq;
+            energy = energy + scaled_velocity * scaled_velocity;
+        }
+        energy
+    }
+
+    /// Update this spring using a pre-computed [`SpringTimeStep`], then apply a constant
+    /// `gravity` acceleration over `delta`, the delta time `time_step` was derived from.
+    ///
+    /// Under gravity, the spring comes to rest offset from its equilibrium; see
+    /// [`Spring::equilibrium_with_gravity`]. The offset is approximate, but converges on
+    /// the exact value as `delta` gets smaller.
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let config = SpringConfig::new(5.0, 0.5);
+    /// let time_step = SpringTimeStep::new(config, 1.0 / 120.0);
+    ///
+    /// let mut spring = Spring::from_equilibrium(0.0_f64);
+    /// for _ in 0..1200 {
+    ///     spring.update_with_gravity(time_step, -9.81, 1.0 / 120.0);
+    /// }
+    ///
+    /// let offset = Spring::equilibrium_with_gravity(config.spring_constant(1.0), 1.0, -9.81);
+    /// assert!((spring.position - offset).abs() < 1e-2);
+    /// ```
+    pub fn update_with_gravity(&mut self, time_step: SpringTimeStep<F>, gravity: F, delta: F) {
+        self.update(time_step);
+        self.velocity = self.velocity + gravity * delta;
+    }
+
+    /// The static offset `mass * gravity / spring_stiffness` from its equilibrium at which a
+    /// spring comes to rest under gravity. See [`SpringConfig::spring_constant`].
+    #[inline]
+    pub fn equilibrium_with_gravity(spring_stiffness: F, mass: F, gravity: F) -> F {
+        mass * gravity / spring_stiffness
+    }
+
+    /// Sum this spring's positions over the next `n` updates of `time_step`, without
+    /// updating it.
+    ///
+    /// Multiplying the result by the delta time of `time_step` approximates the integral of
+    /// the spring's position over that window (a right Riemann sum). This is an approximation
+    /// that improves as the delta time shrinks, not a closed-form integral.
+    pub fn integrate_position(&self, time_step: SpringTimeStep<F>, n: usize) -> F {
+        let mut spring = *self;
+        (0..n).fold(F::zero(), |sum, _| {
+            spring.update(time_step);
+            sum + spring.position
+        })
+    }
+
+    /// Sum this spring's velocities over the next `n` updates of `time_step`, without
+    /// updating it.
+    ///
+    /// Like [`Spring::integrate_position`], multiplying the result by the delta time
+    /// approximates the integral of velocity, which is the spring's net change in position.
+    pub fn integrate_velocity(&self, time_step: SpringTimeStep<F>, n: usize) -> F {
+        let mut spring = *self;
+        (0..n).fold(F::zero(), |sum, _| {
+            spring.update(time_step);
+            sum + spring.velocity
+        })
+    }
+
+    /// Update this spring using a pre-computed [`SpringTimeStep`], as if its equilibrium were
+    /// offset by `equilibrium_offset` for this update only.
+    ///
+    /// Useful for procedural offsets (breathing, foot planting) that vary every frame
+    /// without permanently moving the spring's equilibrium.
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let time_step = SpringTimeStep::new(SpringConfig::new(5.0, 0.5), 0.1);
+    /// let mut spring = Spring::from_equilibrium(1.0);
+    ///
+    /// spring.update_with_offset_equilibrium(time_step, 0.3);
+    /// assert_eq!(spring.equilibrium, 1.0);
+    /// ```
+    pub fn update_with_offset_equilibrium(
+        &mut self,
+        time_step: SpringTimeStep<F>,
+        equilibrium_offset: F,
+    ) {
+        Self::update_internal(
+            &mut self.position,
+            &mut self.velocity,
+            self.equilibrium + equilibrium_offset,
+            time_step,
+        );
+    }
+
+    /// Update this spring using a pre-computed [`SpringTimeStep`], then bring it to a full
+    /// stop at its equilibrium if both its displacement and velocity are below
+    /// `friction_threshold`, modeling static friction.
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let time_step = SpringTimeStep::new(SpringConfig::new(20.0, 0.5), 0.05);
+    /// let mut frictionless = Spring::from_equilibrium(1.0);
+    /// let mut with_friction = frictionless;
+    ///
+    /// for _ in 0..20 {
+    ///     frictionless.update(time_step);
+    ///     with_friction.update_with_static_friction(time_step, 0.01);
+    /// }
+    ///
+    /// assert_ne!(frictionless.position, frictionless.equilibrium);
+    /// assert_eq!(with_friction.position, with_friction.equilibrium);
+    /// assert_eq!(with_friction.velocity, 0.0);
+    /// ```
+    pub fn update_with_static_friction(
+        &mut self,
+        time_step: SpringTimeStep<F>,
+        friction_threshold: F,
+    ) {
+        self.update(time_step);
+        self.snap_if_settled(friction_threshold, friction_threshold);
+    }
+
+    /// Negate this spring's equilibrium, flipping its target to the other side of the origin.
+    ///
+    /// Handy for toggle or flip animations.
+    #[inline]
+    pub fn mirror_equilibrium(&mut self) {
+        self.equilibrium = -self.equilibrium;
+    }
+
+    /// Returns `1` if the spring's displacement changed sign between the `before` and `after`
+    /// snapshots, i.e. it crossed its equilibrium, and `0` otherwise.
+    ///
+    /// Only the two snapshots are compared, so an even number of crossings in between goes
+    /// unnoticed; keep the delta time between snapshots small. Starting exactly at the
+    /// equilibrium does not count as a crossing.
+    pub fn crossings_between(before: Self, after: Self) -> u32 {
+        let before = before.displacement();
+        let after = after.displacement();
+
+        if before != F::zero() && after != F::zero() && before.signum() != after.signum() {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// The time until this spring next crosses its equilibrium, based on its current state.
+    ///
+    /// Under-damped springs always cross their equilibrium. Over-damped and critically damped
+    /// springs cross it at most once, and only if they are moving toward it fast enough;
+    /// otherwise this returns `None`, as it does for [`SpringParams::Static`] springs.
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let params = SpringParams::from(SpringConfig::new(5.0, 0.5));
+    /// let spring = Spring::from_equilibrium(1.0_f64);
+    ///
+    /// let t = spring.equilibrium_crossing_time(params).unwrap();
+    /// let before = spring.peek_stepped(SpringTimeStep::new(params, t - 1e-3));
+    /// let after = spring.peek_stepped(SpringTimeStep::new(params, t + 1e-3));
+    /// assert_eq!(Spring::crossings_between(before, after), 1);
+    ///
+    /// // a spring moving quickly toward its equilibrium crosses it early
+    /// let spring = Spring::from_equilibrium_with_offset(0.0_f64, -1.0).with_velocity(10.0);
+    /// let t = spring.equilibrium_crossing_time(params).unwrap();
+    /// assert!((t - 0.1209).abs() < 1e-4);
+    ///
+    /// // springs that decay along a single exponential mode never reach their equilibrium
+    /// let critical = SpringParams::from(SpringConfig::new(5.0, 1.0));
+    /// let spring = Spring::from_equilibrium_with_offset(0.0_f64, -1.0).with_velocity(5.0);
+    /// assert_eq!(spring.equilibrium_crossing_time(critical), None);
+    ///
+    /// let over = SpringParams::from(SpringConfig::new(5.0, 2.0));
+    /// let SpringParams::OverDamped { z1, .. } = over else { unreachable!() };
+    /// let spring = Spring::from_equilibrium_with_offset(0.0_f64, 1.0).with_velocity(z1);
+    /// assert_eq!(spring.equilibrium_crossing_time(over), None);
+    /// ```
+    pub fn equilibrium_crossing_time(&self, params: SpringParams<F>) -> Option<F>
+    where
+        F: FloatConst,
+    {
+        let displacement = self.displacement();
+        let velocity = self.velocity;
+
+        let time = match params {
+            SpringParams::Static => return None,
+            SpringParams::OverDamped { z1, z2, .. } => {
+                // x(t) = c1 * exp(z1 * t) + c2 * exp(z2 * t)
+                let c1 = (velocity - z2 * displacement) / (z1 - z2);
+                let c2 = displacement - c1;
+                (-c2 / c1).ln() / (z1 - z2)
+            }
+            SpringParams::CriticallyDamped { angular_freq } => {
+                // x(t) = (displacement + c * t) * exp(-angular_freq * t)
+                let c = velocity + angular_freq * displacement;
+                -displacement / c
+            }
+            SpringParams::UnderDamped { oz, a } => {
+                // x(t) = exp(-oz * t) * r * cos(a * t - phase)
+                let phase = ((velocity + oz * displacement) / a).atan2(displacement);
+                // crossings are half a period apart, so reduce the first one into (0, π]
+                let mut angle = phase + F::FRAC_PI_2();
+                if angle <= F::zero() {
+                    angle = angle + F::PI();
+                } else if angle > F::PI() {
+                    angle = angle - F::PI();
+                }
+                angle / a
+            }
+        };
+
+        // NaN (no real solution) fails this comparison as well, and an infinite time means the
+        // spring only approaches its equilibrium
+        (time > F::zero() && time.is_finite()).then_some(time)
+    }
+
+    /// The time until this under-damped spring reaches its equilibrium while moving toward it.
+    ///
+    /// Unlike [`Spring::equilibrium_crossing_time`], this returns `None` unless `params` is
+    /// [`SpringParams::UnderDamped`], and if the spring is already past its equilibrium, i.e.
+    /// moving away from (or resting at) it.
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let params = SpringParams::from(SpringConfig::new(5.0, 0.5));
+    /// let spring = Spring::from_equilibrium_with_offset(0.0_f64, 1.0).with_velocity(-1.0);
+    /// assert!(spring.time_to_equilibrium_crossing(params).is_some());
+    ///
+    /// let spring = spring.with_velocity(1.0);
+    /// assert_eq!(spring.time_to_equilibrium_crossing(params), None);
+    /// ```
+    #[doc(alias = "first_zero_crossing_time")]
+    pub fn time_to_equilibrium_crossing(&self, params: SpringParams<F>) -> Option<F>
+    where
+        F: FloatConst,
+    {
+        let displacement = self.displacement();
+        if !matches!(params, SpringParams::UnderDamped { .. })
+            || displacement == F::zero()
+            || displacement * self.velocity > F::zero()
+        {
+            return None;
+        }
+
+        self.equilibrium_crossing_time(params)
+    }
+
+    /// Create a spring at rest that, once released, overshoots its `equilibrium` by exactly
+    /// `target_overshoot`. Returns `None` unless `params` is [`SpringParams::UnderDamped`].
+    ///
+    /// Released from rest at displacement `d`, an under-damped spring first peaks on the
+    /// other side of its equilibrium after half a period, at `-d * exp(-π * oz / a)`.
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let params = SpringParams::from(SpringConfig::new(5.0, 0.3));
+    /// let time_step = SpringTimeStep::new(params, 0.001);
+    /// let mut spring = Spring::from_configured_overshoot(params, 1.0_f64, 0.25).unwrap();
+    ///
+    /// let mut peak = spring.position;
+    /// for _ in 0..2000 {
+    ///     spring.update(time_step);
+    ///     peak = peak.max(spring.position);
+    /// }
+    /// assert!((peak - 1.25).abs() < 1e-6);
+    /// ```
+    pub fn from_configured_overshoot(
+        params: SpringParams<F>,
+        equilibrium: F,
+        target_overshoot: F,
+    ) -> Option<Self>
+    where
+        F: FloatConst,
+    {
+        match params {
+            SpringParams::UnderDamped { oz, a } => {
+                let offset = -target_overshoot * (F::PI() * oz / a).exp();
+                Some(Self::from_equilibrium_with_offset(equilibrium, offset))
+            }
+            _ => None,
+        }
+    }
+
+    /// Evaluate the `(position, velocity)` of a spring that started in `initial_state` at
+    /// time zero, `t` seconds later, using this spring's equilibrium.
+    ///
+    /// Unlike repeatedly calling [`Spring::update`], this evaluates the closed-form solution
+    /// directly, so it accumulates no floating-point error and yields the same result
+    /// regardless of frame rate. Useful for replays and deterministic simulation.
+    pub fn evaluate_at_time(
+        &self,
+        params: SpringParams<F>,
+        (mut position, mut velocity): (F, F),
+        t: F,
+    ) -> (F, F) {
+        Self::update_internal(
+            &mut position,
+            &mut velocity,
+            self.equilibrium,
+            SpringTimeStep::new(params, t),
+        );
+        (position, velocity)
+    }
+
+    /// Returns this spring with `f` applied to its position, velocity, and equilibrium.
+    #[inline]
+    pub fn map(self, f: impl Fn(F) -> F) -> Self {
+        Self {
+            position: f(self.position),
+            velocity: f(self.velocity),
+            equilibrium: f(self.equilibrium),
+        }
+    }
+
+    /// Returns this spring with `f` applied to its position and equilibrium only.
+    ///
+    /// Useful for conversions that don't apply to velocity, which has different units
+    /// (e.g. offsetting coordinates).
+    #[inline]
+    pub fn map_position_and_equilibrium(self, f: impl Fn(F) -> F) -> Self {
+        Self {
+            position: f(self.position),
+            velocity: self.velocity,
+            equilibrium: f(self.equilibrium),
+        }
+    }
+
+    /// Like [`Spring::update_single`], but clamps `delta` to at most `max_delta` first.
+    ///
+    /// Protects against spikes in delta time (e.g. after a hitch or a paused window) making
+    /// the spring jump far in a single frame. A typical `max_delta` is `0.1` (a 10 FPS minimum);
+    /// the tradeoff is that the spring appears to move slowly while frames are long.
+    #[inline]
+    pub fn update_clamped_delta(&mut self, params: SpringParams<F>, delta: F, max_delta: F) {
+        self.update_single(params, delta.min(max_delta));
+    }
+
+    /// The highest displacement above its equilibrium this spring will reach from now on,
+    /// including its current displacement.
+    ///
+    /// The spring's velocity obeys the same equation of motion as its displacement, starting
+    /// from `(velocity, acceleration)`. Its extrema are therefore the zeros of a "velocity
+    /// spring", found in closed form with [`Spring::equilibrium_crossing_time`]. An
+    /// under-damped spring's first two extrema (one maximum, one minimum) bound every later
+    /// one. Over-damped and critically damped springs have at most one extremum.
+    ///
+    /// A spring that only ever approaches its equilibrium from below returns zero, the value
+    /// it tends toward.
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let params = SpringParams::from(SpringConfig::new(5.0, 0.3));
+    /// let time_step = SpringTimeStep::new(params, 0.001);
+    /// let mut spring = Spring::from_equilibrium_with_offset(1.0_f64, -0.5);
+    ///
+    /// let expected = spring.maximum_future_position_above_equilibrium(params);
+    /// let mut peak = spring.displacement();
+    /// for _ in 0..5000 {
+    ///     spring.update(time_step);
+    ///     peak = peak.max(spring.displacement());
+    /// }
+    /// assert!((peak - expected).abs() < 1e-6);
+    /// ```
+    pub fn maximum_future_position_above_equilibrium(&self, params: SpringParams<F>) -> F
+    where
+        F: FloatConst,
+    {
+        let displacement = self.displacement();
+        let velocity_spring = self.velocity_spring(params);
+
+        let displacement_at = |t: F| {
+            self.evaluate_at_time(params, (self.position, self.velocity), t)
+                .0
+                - self.equilibrium
+        };
+
+        let mut maximum = displacement;
+        match params {
+            SpringParams::Static => return displacement,
+            SpringParams::UnderDamped { a, .. } => {
+                if let Some(t) = velocity_spring.equilibrium_crossing_time(params) {
+                    maximum = maximum
+                        .max(displacement_at(t))
+                        .max(displacement_at(t + F::PI() / a));
+                }
+            }
+            _ => {
+                if let Some(t) = velocity_spring.equilibrium_crossing_time(params) {
+                    maximum = maximum.max(displacement_at(t));
+                }
+                maximum = maximum.max(F::zero());
+            }
+        }
+
+        maximum
+    }
+
+    /// Update this spring over `delta` by numerically integrating its equation of motion
+    /// with the explicit Euler method.
+    ///
+    /// This is far less accurate than [`Spring::update`], which is exact, and becomes
+    /// unstable for stiff springs or large deltas. It exists for comparison and education.
+    pub fn euler_update(&mut self, config: SpringConfig<F>, delta: F) {
+        let acceleration =
+            SpringParams::from(config).acceleration(self.displacement(), self.velocity);
+        self.position = self.position + self.velocity * delta;
+        self.velocity = self.velocity + acceleration * delta;
+    }
+
+    /// Update this spring over `delta` by numerically integrating its equation of motion
+    /// with the midpoint (second-order Runge-Kutta) method.
+    ///
+    /// More accurate than [`Spring::euler_update`], but still only an approximation of
+    /// [`Spring::update`]. It exists for comparison and education.
+    pub fn rk2_update(&mut self, config: SpringConfig<F>, delta: F) {
+        let params = SpringParams::from(config);
+        let half_delta = delta / (F::one() + F::one());
+        let acceleration = params.acceleration(self.displacement(), self.velocity);
+
+        let mid_displacement = self.displacement() + self.velocity * half_delta;
+        let mid_velocity = self.velocity + acceleration * half_delta;
+        let mid_acceleration = params.acceleration(mid_displacement, mid_velocity);
+
+        self.position = self.position + mid_velocity * delta;
+        self.velocity = self.velocity + mid_acceleration * delta;
+    }
+
+    /// Update this spring to follow a target moving at `target_velocity`, where `delta` is the
+    /// delta time `time_step` was derived from.
+    ///
+    /// The equilibrium is set to where the target will be at the end of this update,
+    /// `target_position + target_velocity * delta`. Feeding the target's velocity forward
+    /// like this reduces how far the spring lags behind moving targets.
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let time_step = SpringTimeStep::new(SpringConfig::new(10.0, 1.0), 0.1);
+    /// let mut tracking = Spring::from_equilibrium(0.0_f64);
+    /// let mut following = tracking;
+    ///
+    /// let mut target = 0.0;
+    /// for _ in 0..50 {
+    ///     tracking.update_tracking(time_step, target, 3.0, 0.1);
+    ///     following.equilibrium = target;
+    ///     following.update(time_step);
+    ///     target += 3.0 * 0.1;
+    /// }
+    ///
+    /// assert!((target - tracking.position) < (target - following.position));
+    /// ```
+    pub fn update_tracking(
+        &mut self,
+        time_step: SpringTimeStep<F>,
+        target_position: F,
+        target_velocity: F,
+        delta: F,
+    ) {
+        self.equilibrium = target_position + target_velocity * delta;
+        self.update(time_step);
+    }
+
+    /// Update this spring (and this spring only) with an additional integral term, like the
+    /// I-term of a PI controller, which corrects steady-state offset under a constant load.
+    ///
+    /// `displacement * delta` is accumulated into `integral`, which the caller keeps between
+    /// updates. `integral_gain * integral` then acts as an extra acceleration toward
+    /// equilibrium, applied as an impulse before the regular update.
+    pub fn pi_update(
+        &mut self,
+        params: SpringParams<F>,
+        delta: F,
+        integral_gain: F,
+        integral: &mut F,
+    ) {
+        *integral = *integral + self.displacement() * delta;
+        self.velocity = self.velocity - integral_gain * *integral * delta;
+        self.update_single(params, delta);
+    }
+
+    /// A spring whose displacement and velocity are this spring's velocity and acceleration.
+    ///
+    /// Velocity obeys the same equation of motion as displacement, so the zeros of this
+    /// spring's displacement are the extrema of the original's.
+    fn velocity_spring(&self, params: SpringParams<F>) -> Self {
+        Self {
+            position: self.velocity,
+            velocity: params.acceleration(self.displacement(), self.velocity),
+            equilibrium: F::zero(),
+        }
+    }
+
+    /// The time until, and position of, this under-damped spring's next peak on the other
+    /// side of its equilibrium, as `(time, position)`.
+    ///
+    /// The peak is where the spring's velocity first returns to zero after crossing its
+    /// equilibrium, found in closed form as in
+    /// [`Spring::maximum_future_position_above_equilibrium`]. Returns `None` if the spring is
+    /// moving away from (or resting at) its equilibrium, or if `params` is not
+    /// [`SpringParams::UnderDamped`].
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let params = SpringParams::from(SpringConfig::new(5.0, 0.5));
+    /// let spring = Spring::from_equilibrium_with_offset(0.0_f64, 1.0).with_velocity(-20.0);
+    /// let (time, position) = spring.first_peak(params).unwrap();
+    ///
+    /// // simulate until the velocity first turns back around
+    /// let time_step = SpringTimeStep::new(params, 1e-4);
+    /// let (mut simulated, mut elapsed) = (spring, 0.0);
+    /// while simulated.velocity < 0.0 {
+    ///     simulated.update(time_step);
+    ///     elapsed += 1e-4;
+    /// }
+    ///
+    /// assert!((time - elapsed).abs() < 1e-3);
+    /// assert!((position - simulated.position).abs() < 1e-3);
+    /// assert!(position < 0.0);
+    /// ```
+    pub fn first_peak(&self, params: SpringParams<F>) -> Option<(F, F)>
+    where
+        F: FloatConst,
+    {
+        let displacement = self.displacement();
+        if !matches!(params, SpringParams::UnderDamped { .. })
+            || displacement == F::zero()
+            || displacement * self.velocity > F::zero()
+        {
+            return None;
+        }
+
+        let time = self
+            .velocity_spring(params)
+            .equilibrium_crossing_time(params)?;
+        let (position, _) = self.evaluate_at_time(params, (self.position, self.velocity), time);
+
+        Some((time, position))
+    }
+
+    /// Update this spring (and this spring only) with its angular frequency scaled by
+    /// `stiffness_scale(|displacement|)`, e.g. to model a spring that hardens when stretched.
+    ///
+    /// **Note:** this is an approximation. The parameters are only re-evaluated once per
+    /// update and held constant over `delta`, so prefer small delta times for accuracy.
+    #[inline]
+    pub fn update_nonlinear(
+        &mut self,
+        base_params: SpringParams<F>,
+        delta: F,
+        stiffness_scale: impl Fn(F) -> F,
+    ) {
+        let scale = stiffness_scale(self.displacement().abs());
+        self.update_frequency_scaled(base_params, delta, scale);
+    }
+
+    /// The time constant of an over-damped spring's dominant pole, i.e. the time after which
+    /// its displacement has decayed to roughly `1/e` of its current value. Returns `None`
+    /// unless `params` is [`SpringParams::OverDamped`].
+    ///
+    /// The dominant pole is `z2`, the slower of the two, which is the one closer to zero.
+    #[inline]
+    pub fn overdamped_time_constant(&self, params: SpringParams<F>) -> Option<F> {
+        match params {
+            SpringParams::OverDamped { z2, .. } => Some(F::one() / z2.abs()),
+            _ => None,
+        }
+    }
+
+    /// Update this spring using a pre-computed [`SpringTimeStep`], returning the change in its
+    /// displacement from equilibrium over the update, i.e. how much it stretched.
+    #[inline]
+    pub fn update_returning_displacement_delta(&mut self, time_step: SpringTimeStep<F>) -> F {
+        let previous = self.displacement();
+        self.update(time_step);
+        self.displacement() - previous
+    }
+
+    /// Returns `true` if this spring is at rest, using tolerances derived from its `config`.
+    ///
+    /// The displacement tolerance is `1e-4 * angular_freq` and the velocity tolerance is
+    /// `1e-4 * angular_freq²`: the peak speed of a spring oscillating with an amplitude of
+    /// the displacement tolerance. Stiffer springs move faster, so their velocity tolerance
+    /// grows accordingly.
+    pub fn at_rest(&self, config: SpringConfig<F>) -> bool {
+        let position_threshold = F::from(1e-4).unwrap() * config.angular_freq();
+        let velocity_threshold = position_threshold * config.angular_freq();
+
+        self.displacement().abs() <= position_threshold && self.velocity.abs() <= velocity_threshold
+    }
+
+    /// The initial velocity a critically damped spring with `config`'s angular frequency needs
+    /// so that, starting displaced by `target_displacement` from its equilibrium, it reaches its
+    /// equilibrium exactly `settling_time` seconds later. `config`'s damping ratio is ignored.
+    ///
+    /// A critically damped spring moves as `x(t) = (A + B * t) * exp(-angular_freq * t)`.
+    /// Starting from `x(0) = A = target_displacement` and requiring `x(settling_time) = 0`
+    /// gives `B = -A / settling_time`, so the initial velocity is
+    /// `x'(0) = B - angular_freq * A`.
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let config = SpringConfig::new(5.0_f64, 1.0);
+    /// let velocity =
+    ///     Spring::critically_damped_initial_velocity_for_displacement(config, 2.0, 0.5);
+    ///
+    /// let spring = Spring::from_equilibrium_with_offset(0.0, 2.0).with_velocity(velocity);
+    /// let t = spring.equilibrium_crossing_time(SpringParams::from(config)).unwrap();
+    /// assert!((t - 0.5).abs() < 1e-9);
+    /// ```
+    pub fn critically_damped_initial_velocity_for_displacement(
+        config: SpringConfig<F>,
+        target_displacement: F,
+        settling_time: F,
+    ) -> F {
+        -target_displacement * (F::one() / settling_time + config.angular_freq())
+    }
+
+    /// Update this spring using a pre-computed [`SpringTimeStep`], returning `true` if it
+    /// crossed its equilibrium during the update. See [`Spring::crossings_between`].
+    ///
+    /// A spring starting exactly at its equilibrium does not count as crossing it.
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let time_step = SpringTimeStep::new(SpringConfig::new(5.0, 0.5), 0.1);
+    ///
+    /// let mut spring = Spring::from_equilibrium(0.0);
+    /// spring.velocity = 1.0;
+    /// assert!(!spring.update_checking_crossing(time_step));
+    /// ```
+    #[inline]
+    pub fn update_checking_crossing(&mut self, time_step: SpringTimeStep<F>) -> bool {
+        let previous = *self;
+        self.update(time_step);
+        Self::crossings_between(previous, *self) > 0
+    }
+
+    /// Update this spring using a pre-computed [`SpringTimeStep`], limiting the change in its
+    /// velocity to `max_acceleration * delta`, where `delta` is the delta time `time_step` was
+    /// derived from.
+    ///
+    /// Limiting acceleration smooths out sudden changes in motion at the cost of following
+    /// the spring less closely; the spring still settles, just more slowly.
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let time_step = SpringTimeStep::new(SpringConfig::new(10.0, 1.0), 1.0 / 60.0);
+    /// let mut spring = Spring::from_equilibrium(5.0_f64);
+    ///
+    /// let mut previous_velocity = spring.velocity;
+    /// for _ in 0..600 {
+    ///     spring.update_accel_limited(time_step, 20.0, 1.0 / 60.0);
+    ///     assert!((spring.velocity - previous_velocity).abs() <= 20.0 / 60.0 + 1e-9);
+    ///     previous_velocity = spring.velocity;
+    /// }
+    /// assert!(spring.displacement().abs() < 1e-3);
+    /// ```
+    pub fn update_accel_limited(
+        &mut self,
+        time_step: SpringTimeStep<F>,
+        max_acceleration: F,
+        delta: F,
+    ) {
+        let previous_velocity = self.velocity;
+        self.update(time_step);
+
+        let max_change = max_acceleration * delta;
+        let change = (self.velocity - previous_velocity)
+            .max(-max_change)
+            .min(max_change);
+        self.velocity = previous_velocity + change;
+    }
+
+    /// Copy this spring's position and velocity into `target`, keeping `target`'s equilibrium.
+    ///
+    /// Useful for handing motion off from one spring to another with a new equilibrium.
+    #[inline]
+    pub fn copy_dynamics_to(&self, target: &mut Self) {
+        target.position = self.position;
+        target.velocity = self.velocity;
+    }
+
+    /// Copy this spring's position, velocity, and equilibrium into `target`.
+    #[inline]
+    pub fn copy_state_to(&self, target: &mut Self) {
+        *target = *self;
+    }
+
+    /// The distance between this spring's position and its equilibrium, i.e. the absolute
+    /// value of its [displacement](Spring::displacement).
+    #[inline]
+    pub fn equilibrium_distance(&self) -> F {
+        self.displacement().abs()
+    }
+
+    /// Returns `true` if this spring's position is above its equilibrium.
+    #[inline]
+    pub fn is_above_equilibrium(&self) -> bool {
+        self.position > self.equilibrium
+    }
+
+    /// Returns `true` if this spring's position is below its equilibrium.
+    #[inline]
+    pub fn is_below_equilibrium(&self) -> bool {
+        self.position < self.equilibrium
+    }
+
+    /// The time until this over-damped spring settles to within `fraction` of its current
+    /// displacement (e.g. `0.01` for 1%), after which it stays within it. Returns `None`
+    /// unless `params` is [`SpringParams::OverDamped`], the spring is displaced, and
+    /// `fraction` is between zero and one.
+    ///
+    /// The spring moves as `x(t) = c1 * exp(z1 * t) + c2 * exp(z2 * t)`, which has no
+    /// closed-form inverse, so the time is found by bisection. At most one extremum (found in
+    /// closed form) splits the motion into segments where `|x(t)|` is monotonic, which
+    /// keeps the bisection well-defined.
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let params = SpringParams::from(SpringConfig::new(5.0, 2.0));
+    /// let spring = Spring::from_equilibrium(1.0_f64);
+    ///
+    /// let t = spring.overdamped_time_to_fraction(params, 0.01).unwrap();
+    /// let settled = spring.peek_stepped(SpringTimeStep::new(params, t));
+    /// assert!((settled.displacement().abs() - 0.01).abs() < 1e-9);
+    /// ```
+    pub fn overdamped_time_to_fraction(&self, params: SpringParams<F>, fraction: F) -> Option<F>
+    where
+        F: FloatConst,
+    {
+        let displacement = self.displacement();
+        if !matches!(params, SpringParams::OverDamped { .. })
+            || displacement == F::zero()
+            || fraction <= F::zero()
+            || fraction >= F::one()
+        {
+            return None;
+        }
+
+        let target = fraction * displacement.abs();
+        let distance_at = |t: F| {
+            let (position, _) = self.evaluate_at_time(params, (self.position, self.velocity), t);
+            (position - self.equilibrium).abs() - target
+        };
+
+        // find a segment where `|x(t)|` decreases monotonically through `target`
+        let (start, end) = match self
+            .velocity_spring(params)
+            .equilibrium_crossing_time(params)
+        {
+            Some(extremum) if distance_at(extremum) <= F::zero() => {
+                // the spring crosses its equilibrium first, and never leaves `target` again
+                let crossing = self.equilibrium_crossing_time(params).unwrap_or(extremum);
+                (F::zero(), crossing)
+            }
+            extremum => {
+                let start = extremum.unwrap_or(F::zero());
+                let mut end = start + params.characteristic_time()?;
+                while distance_at(end) > F::zero() {
+                    end = end + end;
+                }
+                (start, end)
+            }
+        };
+
+        Some(Self::bisect(distance_at, start, end))
+    }
+
+    /// Find the root of `f`, which must be decreasing through zero between `start` and `end`.
+    fn bisect(f: impl Fn(F) -> F, mut start: F, mut end: F) -> F {
+        let two = F::one() + F::one();
+        for _ in 0..100 {
+            let mid = (start + end) / two;
+            if mid <= start || mid >= end {
+                break;
+            }
+
+            if f(mid) > F::zero() {
+                start = mid;
+            } else {
+                end = mid;
+            }
+        }
+
+        (start + end) / two
+    }
+
+    /// Update this spring using a pre-computed [`SpringTimeStep`], first lowering its
+    /// equilibrium if its predicted peak would exceed `position_limit`.
+    ///
+    /// The peak is predicted with [`Spring::maximum_future_position_above_equilibrium`], and
+    /// the equilibrium is lowered by however much the peak exceeds the limit. This is an
+    /// approximation: moving the equilibrium also changes the spring's displacement, and with
+    /// it the peak, so the spring may still briefly exceed `position_limit`.
+    pub fn update_equilibrium_corrected(
+        &mut self,
+        params: SpringParams<F>,
+        time_step: SpringTimeStep<F>,
+        position_limit: F,
+    ) where
+        F: FloatConst,
+    {
+        let peak = self.equilibrium + self.maximum_future_position_above_equilibrium(params);
+        if peak > position_limit {
+            self.equilibrium = self.equilibrium - (peak - position_limit);
+        }
+
+        self.update(time_step);
+    }
+
+    /// This spring's displacement relative to the characteristic length scale `1 / angular_freq`
+    /// of `params`, i.e. `displacement * angular_freq`. Returns the raw displacement for
+    /// [`SpringParams::Static`] springs.
+    ///
+    /// Together with [`Spring::normalized_velocity`], this allows comparing springs with
+    /// different configurations.
+    pub fn normalized_displacement(&self, params: SpringParams<F>) -> F {
+        match params {
+            SpringParams::Static => self.displacement(),
+            _ => self.displacement() * params.angular_freq(),
+        }
+    }
+
+    /// This spring's velocity divided by the angular frequency of `params`. Returns the raw
+    /// velocity for [`SpringParams::Static`] springs.
+    pub fn normalized_velocity(&self, params: SpringParams<F>) -> F {
+        match params {
+            SpringParams::Static => self.velocity,
+            _ => self.velocity / params.angular_freq(),
+        }
+    }
+
+    /// Returns this spring as a `[position, velocity, equilibrium]` array.
+    #[inline]
+    pub fn to_array(&self) -> [F; 3] {
+        [self.position, self.velocity, self.equilibrium]
+    }
+
+    /// Create a spring from a `[position, velocity, equilibrium]` array.
+    #[inline]
+    pub fn from_array([position, velocity, equilibrium]: [F; 3]) -> Self {
+        Self {
+            position,
+            velocity,
+            equilibrium,
+        }
+    }
+
+    /// The component of this spring's velocity directed toward its equilibrium: positive when
+    /// moving toward it, negative when moving away. Zero when the spring is at its equilibrium.
+    pub fn velocity_toward_equilibrium(&self) -> F {
+        let displacement = self.displacement();
+        if displacement.abs() < F::epsilon() {
+            return F::zero();
+        }
+
+        -self.velocity * displacement.signum()
+    }
+
+    /// The integral of this spring's displacement from now until it settles, i.e. the total
+    /// signed area under its motion curve. Returns `None` if the spring never settles
+    /// ([`SpringParams::Static`], or an under-damped spring with no damping).
+    ///
+    /// Integrating the equation of motion `x'' + c * x' + k * x = 0` from zero to infinity
+    /// gives `-velocity - c * displacement + k * integral = 0`, where `k = angular_freq²`
+    /// and `c = 2 * damping_ratio * angular_freq`. For a critically damped spring this is
+    /// `2 * displacement / angular_freq + velocity / angular_freq²`.
+    pub fn total_integrated_displacement(&self, params: SpringParams<F>) -> Option<F> {
+        let (stiffness, damping) = params.stiffness_and_damping();
+        if stiffness <= F::zero() || damping <= F::zero() {
+            return None;
+        }
+
+        Some((self.velocity + damping * self.displacement()) / stiffness)
+    }
+
+    /// Update this spring using a pre-computed [`SpringTimeStep`], calling `on_crossing` with
+    /// the updated spring if it crossed its equilibrium. See [`Spring::update_checking_crossing`].
+    #[inline]
+    pub fn update_with_callback(
+        &mut self,
+        time_step: SpringTimeStep<F>,
+        mut on_crossing: impl FnMut(&Self),
+    ) {
+        if self.update_checking_crossing(time_step) {
+            on_crossing(self);
+        }
+    }
+
+    /// Update this spring using a pre-computed [`SpringTimeStep`], appending its new
+    /// `(position, velocity)` to `log`.
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let time_step = SpringTimeStep::new(SpringConfig::new(5.0, 0.5), 0.1);
+    /// let mut spring = Spring::from_equilibrium(1.0);
+    ///
+    /// let mut log = Vec::new();
+    /// for _ in 0..32 {
+    ///     spring.update_with_log(time_step, &mut log);
+    /// }
+    ///
+    /// let mut csv = String::from("position,velocity\n");
+    /// for (position, velocity) in &log {
+    ///     csv.push_str(&format!("{position},{velocity}\n"));
+    /// }
+    /// assert_eq!(csv.lines().count(), 33);
+    /// ```
+    #[inline]
+    pub fn update_with_log(&mut self, time_step: SpringTimeStep<F>, log: &mut Vec<(F, F)>) {
+        log.push(self.update_returning(time_step));
+    }
+
+    /// A parameter-free measure of how active this spring is, `displacement² + velocity²`.
+    ///
+    /// This is not physical energy (it lacks mass and stiffness), but values near zero still
+    /// mean the spring has settled, making it handy for sorting springs by activity.
+    #[inline]
+    pub fn activity_level(&self) -> F {
+        self.displacement().powi(2) + self.velocity.powi(2)
+    }
+
+    /// Update this spring (and this spring only), using `positive_params` while it is above
+    /// its equilibrium and `negative_params` while it is below.
+    ///
+    /// **Note:** switching params between updates is an approximation, since the spring's
+    /// solution assumes its params stay constant. It looks fine with small delta times.
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let stiff = SpringParams::from(SpringConfig::new(20.0, 1.0));
+    /// let soft = SpringParams::from(SpringConfig::new(5.0, 1.0));
+    ///
+    /// let mut stretched = Spring::from_equilibrium_with_offset(0.0_f64, 1.0);
+    /// let mut compressed = Spring::from_equilibrium_with_offset(0.0_f64, -1.0);
+    /// for _ in 0..10 {
+    ///     stretched.update_asymmetric(stiff, soft, 0.01);
+    ///     compressed.update_asymmetric(stiff, soft, 0.01);
+    /// }
+    ///
+    /// assert!(stretched.displacement().abs() < compressed.displacement().abs());
+    /// ```
+    pub fn update_asymmetric(
+        &mut self,
+        positive_params: SpringParams<F>,
+        negative_params: SpringParams<F>,
+        delta: F,
+    ) {
+        let params = if self.displacement() >= F::zero() {
+            positive_params
+        } else {
+            negative_params
+        };
+        self.update_single(params, delta);
+    }
+
+    /// Update this spring using a pre-computed [`SpringTimeStep`], then snap it to rest at its
+    /// equilibrium if its displacement is within `snap_zone`, regardless of its velocity.
+    ///
+    /// The spring approaches naturally and then snaps cleanly, rather than creeping toward its
+    /// equilibrium forever. A `snap_zone` of zero behaves exactly like [`Spring::update`].
+    #[inline]
+    pub fn update_with_snap(&mut self, time_step: SpringTimeStep<F>, snap_zone: F) {
+        self.update(time_step);
+        self.snap_if_settled(snap_zone, F::infinity());
+    }
+
+    /// Update this spring using a pre-computed [`SpringTimeStep`], first shrinking its
+    /// displacement by a factor of `exp(-ease_factor)`, which adds an exponential ease-out.
+    ///
+    /// **Note:** this is a stylistic, non-physical modification that moves the spring toward
+    /// its equilibrium faster than the spring alone would. An `ease_factor` of zero behaves
+    /// exactly like [`Spring::update`].
+    pub fn update_with_ease(&mut self, time_step: SpringTimeStep<F>, ease_factor: F) {
+        if ease_factor != F::zero() {
+            self.position = self.equilibrium + self.displacement() * (-ease_factor).exp();
+        }
+        self.update(time_step);
+    }
+
+    /// Update this spring using a pre-computed [`SpringTimeStep`], first ensuring that, while
+    /// displaced by more than `snap_zone`, it moves toward its equilibrium at a speed of at
+    /// least `min_approach_speed`. Once within `snap_zone`, it snaps to rest at its equilibrium
+    /// as in [`Spring::update_with_snap`].
+    ///
+    /// This avoids the imperceptibly slow creep of a spring that is almost, but not quite, at
+    /// rest. Keep `snap_zone` at least as large as the distance covered at `min_approach_speed`
+    /// in one time step, or the spring may step over it and jitter around its equilibrium.
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let time_step = SpringTimeStep::new(SpringConfig::new(5.0, 1.0), 1.0 / 60.0);
+    /// let mut spring = Spring::from_equilibrium_with_offset(0.0_f64, 1.0);
+    ///
+    /// // without a minimum approach speed, this takes 80 updates
+    /// for _ in 0..60 {
+    ///     spring.update_with_min_speed(time_step, 0.5, 0.01);
+    /// }
+    /// assert_eq!(spring, spring.resting_state());
+    /// ```
+    pub fn update_with_min_speed(
+        &mut self,
+        time_step: SpringTimeStep<F>,
+        min_approach_speed: F,
+        snap_zone: F,
+    ) {
+        let displacement = self.displacement();
+        if displacement.abs() > snap_zone && self.speed() < min_approach_speed {
+            self.velocity = -displacement.signum() * min_approach_speed;
+        }
+        self.update_with_snap(time_step, snap_zone);
+    }
+
+    /// The state this spring will eventually settle into: at rest at its equilibrium.
+    #[inline]
+    pub fn resting_state(&self) -> Self {
+        Self::from_equilibrium_at_position(self.equilibrium, self.equilibrium)
+    }
+
+    /// Returns `true` if this spring's position and velocity are within `epsilon` of its
+    /// [resting state](Spring::resting_state).
+    #[inline]
+    pub fn is_at_resting_state(&self, epsilon: F) -> bool {
+        self.displacement().abs() <= epsilon && self.velocity.abs() <= epsilon
+    }
+
+    /// The speed of this spring, i.e. the absolute value of its (signed) velocity.
+    #[inline]
+    pub fn speed(&self) -> F {
+        self.velocity.abs()
+    }
+
+    /// The direction this spring is moving in: `1` for positive velocities, `-1` for negative
+    /// velocities, and `0` when it isn't moving.
+    #[inline]
+    pub fn direction(&self) -> F {
+        if self.velocity == F::zero() {
+            F::zero()
+        } else {
+            self.velocity.signum()
+        }
+    }
+}
+
+/// Converts a spring into a `(position, velocity, equilibrium)` tuple.
+impl<F: Float> From<Spring<F>> for (F, F, F) {
+    fn from(spring: Spring<F>) -> Self {
+        (spring.position, spring.velocity, spring.equilibrium)
+    }
+}
+
+/// Converts a `(position, velocity, equilibrium)` tuple into a spring.
+impl<F: Float> From<(F, F, F)> for Spring<F> {
+    fn from((position, velocity, equilibrium): (F, F, F)) -> Self {
+        Self {
+            position,
+            velocity,
+            equilibrium,
+        }
+    }
+}
+
+/// Scales every field of a spring, **including its equilibrium**, by a scalar.
+///
+/// This is useful for changing units, but may not be what you want if the spring's
+/// equilibrium should stay put; see [`Spring::scale_dynamics`] instead.
+impl<F: Float> Mul<F> for Spring<F> {
+    type Output = Self;
+
+    fn mul(self, rhs: F) -> Self {
+        Self {
+            position: self.position * rhs,
+            velocity: self.velocity * rhs,
+            equilibrium: self.equilibrium * rhs,
+        }
+    }
+}
+
+impl<F: Float> MulAssign<F> for Spring<F> {
+    fn mul_assign(&mut self, rhs: F) {
+        *self = *self * rhs;
+    }
+}
+
+/// Negates every field of a spring, mirroring it across the origin.
+///
+/// Like the other arithmetic operators on [`Spring`], this is a geometric operation; the
+/// result is not necessarily a spring attached to any physical system.
+impl<F: Float> Neg for Spring<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            position: -self.position,
+            velocity: -self.velocity,
+            equilibrium: -self.equilibrium,
+        }
+    }
+}
+
+/// Adds the corresponding fields of two springs, superimposing their motions.
+impl<F: Float> Add for Spring<F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            position: self.position + rhs.position,
+            velocity: self.velocity + rhs.velocity,
+            equilibrium: self.equilibrium + rhs.equilibrium,
+        }
+    }
+}
+
+/// Subtracts the corresponding fields of two springs.
+impl<F: Float> Sub for Spring<F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            position: self.position - rhs.position,
+            velocity: self.velocity - rhs.velocity,
+            equilibrium: self.equilibrium - rhs.equilibrium,
+        }
+    }
+}
+
+/// A fixed-size collection of springs that all share the same spring parameters.
+/// Useful for creating springs over multiple dimensions (i.e. 2D or 3D springs).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct SpringCollection<F, const N: usize> {
+    params: SpringParams<F>,
+    positions: [F; N],
+    velocities: [F; N],
+    equilibriums: [F; N],
+}
+
+impl<F: Float, const N: usize, T> From<T> for SpringCollection<F, N>
+where
+    T: Into<SpringParams<F>>,
+{
+    fn from(params: T) -> Self {
+        Self {
+            params: params.into(),
+            positions: [F::zero(); N],
+            velocities: [F::zero(); N],
+            equilibriums: [F::zero(); N],
+        }
+    }
+}
+
+impl<F: Float, const N: usize> SpringCollection<F, N> {
+    /// Construct `N` springs, all starting at a specified `equilibrium`.
+    pub fn from_equilibrium(params: impl Into<SpringParams<F>>, equilibrium: F) -> Self {
+        Self {
+            params: params.into(),
+            positions: [F::zero(); N],
+            velocities: [F::zero(); N],
+            equilibriums: [equilibrium; N],
+        }
+    }
+
+    /// Construct `N` springs, each with a particular equilibrium.
+    pub fn from_equilibriums(params: impl Into<SpringParams<F>>, equilibriums: [F; N]) -> Self {
+        Self {
+            params: params.into(),
+            positions: [F::zero(); N],
+            velocities: [F::zero(); N],
+            equilibriums,
+        }
+    }
+
+    /// Returns this collection with each spring's equilibrium set to the corresponding value
+    /// in `equilibriums`.
+    #[inline]
+    pub fn with_equilibriums(mut self, equilibriums: [F; N]) -> Self {
+        self.equilibriums = equilibriums;
+        self
+    }
+
+    /// Returns this collection with every spring's equilibrium set to `equilibrium`.
+    #[inline]
+    pub fn with_all_equilibriums(self, equilibrium: F) -> Self {
+        self.with_equilibriums([equilibrium; N])
+    }
+
+    /// Update all springs over the specified delta. Constructs a new [`SpringTimeStep`]
+    /// for this usage.
+    #[inline]
+    pub fn update(&mut self, delta: F) {
+        self.update_with(SpringTimeStep::new(self.params, delta));
+    }
+
+    /// Update all springs using the specified `time_step`.
+    ///
+    /// **Note:** this time step need not be derived from [`SpringCollection::params`].
+    /// The implementation of this method uses all constants from `time_step`.
+    #[inline]
     pub fn update_with(&mut self, time_step: SpringTimeStep<F>) {
         for i in 0..N {
-            Spring::update_internal(
+            self.update_index(i, time_step);
+        }
+    }
+
+    /// Update all springs over the specified delta, except those at `paused_indices`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index in `paused_indices` is out of bounds.
+    pub fn update_paused(&mut self, paused_indices: &[usize], delta: F) {
+        let mut paused = [false; N];
+        for &i in paused_indices {
+            paused[i] = true;
+        }
+
+        let time_step = SpringTimeStep::new(self.params, delta);
+        for (i, paused) in paused.into_iter().enumerate() {
+            if !paused {
+                self.update_index(i, time_step);
+            }
+        }
+    }
+
+    /// Update only the springs at `active_indices` over the specified delta.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index in `active_indices` is out of bounds.
+    pub fn update_only(&mut self, active_indices: &[usize], delta: F) {
+        let time_step = SpringTimeStep::new(self.params, delta);
+        for &i in active_indices {
+            self.update_index(i, time_step);
+        }
+    }
+
+    #[inline]
+    fn update_index(&mut self, i: usize, time_step: SpringTimeStep<F>) {
+        Spring::update_internal(
+            &mut self.positions[i],
+            &mut self.velocities[i],
+            self.equilibriums[i],
+            time_step,
+        );
+    }
+
+    /// Snap every spring in this collection to rest at its equilibrium if it has settled.
+    /// See [`Spring::snap_if_settled`].
+    #[inline]
+    pub fn snap_all_if_settled(&mut self, position_threshold: F, velocity_threshold: F) {
+        for i in 0..N {
+            Spring::snap_internal(
                 &mut self.positions[i],
                 &mut self.velocities[i],
                 self.equilibriums[i],
-                time_step,
+                position_threshold,
+                velocity_threshold,
             );
         }
     }
+
+    /// Update all springs over the specified delta, returning the change in each spring's position.
+    pub fn update_returning_deltas(&mut self, delta: F) -> [F; N] {
+        let previous = self.positions;
+        self.update(delta);
+
+        let mut deltas = previous;
+        for (delta, position) in deltas.iter_mut().zip(self.positions) {
+            *delta = position - *delta;
+        }
+        deltas
+    }
+
+    /// Update all springs over the specified delta, returning each spring's new velocity.
+    #[inline]
+    pub fn update_returning_velocities(&mut self, delta: F) -> [F; N] {
+        self.update(delta);
+        self.velocities
+    }
+
+    /// Update all springs over the specified delta, returning each spring's new position.
+    #[inline]
+    pub fn update_all_returning_positions(&mut self, delta: F) -> [F; N] {
+        self.update(delta);
+        self.positions
+    }
+
+    /// Negate every spring's equilibrium. See [`Spring::mirror_equilibrium`].
+    #[inline]
+    pub fn mirror_all_equilibriums(&mut self) {
+        for equilibrium in &mut self.equilibriums {
+            *equilibrium = -*equilibrium;
+        }
+    }
+
+    /// Update all springs over the specified delta, clamped to at most `max_delta`.
+    /// See [`Spring::update_clamped_delta`].
+    #[inline]
+    pub fn update_clamped(&mut self, delta: F, max_delta: F) {
+        self.update(delta.min(max_delta));
+    }
+
+    /// Construct a collection from exactly `N` springs. Returns `None` if `springs` yields
+    /// fewer or more than `N` springs.
+    pub fn from_iter(
+        params: impl Into<SpringParams<F>>,
+        springs: impl IntoIterator<Item = Spring<F>>,
+    ) -> Option<Self> {
+        let mut collection = Self::from(params);
+        let mut springs = springs.into_iter();
+
+        for i in 0..N {
+            let spring = springs.next()?;
+            collection.positions[i] = spring.position;
+            collection.velocities[i] = spring.velocity;
+            collection.equilibriums[i] = spring.equilibrium;
+        }
+
+        match springs.next() {
+            Some(_) => None,
+            None => Some(collection),
+        }
+    }
+
+    /// Returns this collection with `f` applied to every spring.
+    #[inline]
+    pub fn map_springs(mut self, f: impl Fn(Spring<F>) -> Spring<F>) -> Self {
+        self.map_springs_in_place(|spring| *spring = f(*spring));
+        self
+    }
+
+    /// Apply `f` to every spring in this collection.
+    pub fn map_springs_in_place(&mut self, f: impl Fn(&mut Spring<F>)) {
+        for i in 0..N {
+            let mut spring = Spring {
+                position: self.positions[i],
+                velocity: self.velocities[i],
+                equilibrium: self.equilibriums[i],
+            };
+            f(&mut spring);
+
+            self.positions[i] = spring.position;
+            self.velocities[i] = spring.velocity;
+            self.equilibriums[i] = spring.equilibrium;
+        }
+    }
+
+    /// Returns `true` if every spring has the same equilibrium.
+    #[inline]
+    pub fn equilibriums_are_uniform(&self) -> bool {
+        Self::is_uniform(&self.equilibriums)
+    }
+
+    /// Returns `true` if every spring has the same position.
+    #[inline]
+    pub fn positions_are_uniform(&self) -> bool {
+        Self::is_uniform(&self.positions)
+    }
+
+    /// Returns `true` if every spring has the same velocity.
+    #[inline]
+    pub fn velocities_are_uniform(&self) -> bool {
+        Self::is_uniform(&self.velocities)
+    }
+
+    /// The `(min, max)` equilibrium across all springs. Returns `(inf, -inf)` if `N` is zero.
+    pub fn equilibrium_range(&self) -> (F, F) {
+        self.equilibriums.iter().fold(
+            (F::infinity(), F::neg_infinity()),
+            |(min, max), &equilibrium| (min.min(equilibrium), max.max(equilibrium)),
+        )
+    }
+
+    fn is_uniform(values: &[F; N]) -> bool {
+        values.windows(2).all(|pair| pair[0] == pair[1])
+    }
+
+    /// Set every spring's equilibrium, update all springs over the specified delta, and
+    /// return their new positions. See [`SpringCollection::tick`] for a shorter name.
+    #[inline]
+    pub fn update_with_per_spring_equilibriums_and_return_positions(
+        &mut self,
+        delta: F,
+        equilibriums: [F; N],
+    ) -> [F; N] {
+        self.equilibriums = equilibriums;
+        self.update_all_returning_positions(delta)
+    }
+
+    /// Advance all springs by `delta` toward `targets`, returning their new positions
+    /// for rendering.
+    #[inline]
+    pub fn tick(&mut self, delta: F, targets: [F; N]) -> [F; N] {
+        self.update_with_per_spring_equilibriums_and_return_positions(delta, targets)
+    }
+
+    /// Update all springs over the specified delta, then apply a uniform drag, scaling every
+    /// spring's velocity by `exp(-drag_coefficient * delta)`.
+    ///
+    /// Since every dimension loses the same fraction of its velocity, drag slows the motion
+    /// down without changing its direction.
+    ///
+    /// ```
+    /// # use damped_springs::prelude::*;
+    /// let config = SpringConfig::new(5.0, 0.5);
+    /// let mut dragged = SpringCollection::from_equilibriums(config, [1.0_f64, 2.0]);
+    /// let mut free = dragged.clone();
+    ///
+    /// dragged.update_with_drag(0.1, 3.0);
+    /// free.update(0.1);
+    ///
+    /// let [dx, dy] = *dragged.velocities();
+    /// let [fx, fy] = *free.velocities();
+    /// assert!(dx.abs() < fx.abs() && dy.abs() < fy.abs());
+    /// assert!((dx / dy - fx / fy).abs() < 1e-12);
+    /// ```
+    pub fn update_with_drag(&mut self, delta: F, drag_coefficient: F) {
+        self.update(delta);
+
+        let factor = (-drag_coefficient * delta).exp();
+        for velocity in &mut self.velocities {
+            *velocity = *velocity * factor;
+        }
+    }
 }
 
 macro_rules! impl_collection_props {
-    ( $prop:ident , $prop_mut:ident ) => {
+    ( $prop:ident , $prop_mut:ident , $prop_slice:ident ) => {
         impl<F: Float, const N: usize> SpringCollection<F, N> {
-            #[doc = concat!("The array of current spring ", stringify!($plural), ".")]
+            #[doc = concat!("The array of current spring ", stringify!($prop), ".")]
             #[inline]
             pub fn $prop(&self) -> &[F; N] {
                 &self.$prop
             }
 
+            #[doc = concat!("The current spring ", stringify!($prop), " as a slice.")]
+            #[inline]
+            pub fn $prop_slice(&self) -> &[F] {
+                &self.$prop
+            }
+
             #[doc = concat!("Mutable reference of current spring ", stringify!($prop), ".")]
             #[inline]
             pub fn $prop_mut(&mut self) -> &mut [F; N] {
@@ -372,9 +2709,9 @@ macro_rules! impl_collection_props {
     };
 }
 
-impl_collection_props!(positions, positions_mut);
-impl_collection_props!(velocities, velocities_mut);
-impl_collection_props!(equilibriums, equilibriums_mut);
+impl_collection_props!(positions, positions_mut, positions_slice);
+impl_collection_props!(velocities, velocities_mut, velocities_slice);
+impl_collection_props!(equilibriums, equilibriums_mut, equilibriums_slice);
 
 impl<F: Float, const N: usize> From<SpringCollection<F, N>> for [Spring<F>; N] {
     fn from(value: SpringCollection<F, N>) -> Self {
@@ -391,3 +2728,120 @@ impl<F: Float, const N: usize> From<SpringCollection<F, N>> for [Spring<F>; N] {
         })
     }
 }
+
+impl<F: Float, const N: usize> IntoIterator for SpringCollection<F, N> {
+    type Item = Spring<F>;
+    type IntoIter = std::array::IntoIter<Spring<F>, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        <[Spring<F>; N]>::from(self).into_iter()
+    }
+}
+
+/// A [`Spring`] that remembers its last `H` positions, e.g. for motion blur or camera smoothing.
+///
+/// ```
+/// # use damped_springs::prelude::*;
+/// let time_step = SpringTimeStep::new(SpringConfig::new(5.0, 0.5), 0.1);
+/// let mut spring = SpringWithHistory::<_, 4>::new(Spring::from_equilibrium(1.0));
+///
+/// let mut positions = Vec::new();
+/// for _ in 0..6 {
+///     spring.update(time_step);
+///     positions.push(spring.spring().position);
+/// }
+///
+/// assert_eq!(spring.history_ordered(), positions[2..]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpringWithHistory<F, const H: usize> {
+    spring: Spring<F>,
+    history: [F; H],
+    next: usize,
+}
+
+impl<F: Float, const H: usize> SpringWithHistory<F, H> {
+    /// Wrap a spring, filling its history with its current position.
+    pub fn new(spring: Spring<F>) -> Self {
+        Self {
+            spring,
+            history: [spring.position; H],
+            next: 0,
+        }
+    }
+
+    /// The wrapped spring.
+    #[inline]
+    pub fn spring(&self) -> &Spring<F> {
+        &self.spring
+    }
+
+    /// Mutable reference to the wrapped spring. Changes are not recorded in its history.
+    #[inline]
+    pub fn spring_mut(&mut self) -> &mut Spring<F> {
+        &mut self.spring
+    }
+
+    /// Update the spring using a pre-computed [`SpringTimeStep`], recording its new position.
+    pub fn update(&mut self, time_step: SpringTimeStep<F>) {
+        self.spring.update(time_step);
+
+        if H > 0 {
+            self.history[self.next] = self.spring.position;
+            self.next = (self.next + 1) % H;
+        }
+    }
+
+    /// The ring buffer of past positions. Its order depends on how many updates have been
+    /// recorded; see [`SpringWithHistory::history_ordered`].
+    #[inline]
+    pub fn history(&self) -> &[F; H] {
+        &self.history
+    }
+
+    /// The past positions, from oldest to newest.
+    pub fn history_ordered(&self) -> [F; H] {
+        let mut ordered = self.history;
+        ordered.rotate_left(self.next);
+        ordered
+    }
+}
+
+/// Turns a spring configuration into an easing curve.
+///
+/// The curve is the motion of a spring released from rest at `0` toward an equilibrium of `1`,
+/// so it starts at `0` and settles at `1`, overshooting if the spring is under-damped.
+///
+/// ```
+/// # use damped_springs::prelude::*;
+/// let easing = SpringInterpolator::from_config(SpringConfig::new(10.0_f64, 1.0));
+/// assert_eq!(easing.eval(0.0), 0.0);
+/// assert!((easing.eval(1.0) - 1.0).abs() < 1e-3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpringInterpolator<F> {
+    params: SpringParams<F>,
+}
+
+impl<F: Float> SpringInterpolator<F> {
+    /// Construct an easing curve from a spring configuration.
+    pub fn from_config(config: SpringConfig<F>) -> Self {
+        Self {
+            params: config.into(),
+        }
+    }
+
+    /// Evaluate the easing curve at time `t`.
+    pub fn eval(&self, t: F) -> F {
+        // the remaining displacement of a spring released from rest at a displacement of 1
+        F::one() - SpringTimeStep::new(self.params, t).pp
+    }
+
+    /// Sample the easing curve at `n` evenly spaced times over `[0, 1]`, e.g. for a lookup table.
+    pub fn sample_table(&self, n: usize) -> Vec<F> {
+        let last = F::from(n.saturating_sub(1).max(1)).unwrap();
+        (0..n)
+            .map(|i| self.eval(F::from(i).unwrap() / last))
+            .collect()
+    }
+}
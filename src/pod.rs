@@ -0,0 +1,35 @@
+//! `bytemuck` support, allowing springs and time steps to be cast to and from bytes, e.g. to
+//! upload spring state to the GPU.
+//!
+//! ```
+//! # use damped_springs::prelude::*;
+//! let springs = [
+//!     Spring::from_array([1.0_f32, 2.0, 3.0]),
+//!     Spring::from_array([4.0, 5.0, 6.0]),
+//! ];
+//!
+//! let flat: &[f32] = bytemuck::cast_slice(&springs);
+//! assert_eq!(flat, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+//! ```
+
+use bytemuck::{Pod, Zeroable};
+
+use crate::{Spring, SpringTimeStep};
+
+macro_rules! impl_pod {
+    ( $($ty:ty),* ) => {
+        $(
+            // SAFETY: `repr(C)` structs made up entirely of a single float type,
+            // so they have no padding and any bit pattern is valid.
+            unsafe impl Zeroable for $ty {}
+            unsafe impl Pod for $ty {}
+        )*
+    };
+}
+
+impl_pod!(
+    Spring<f32>,
+    Spring<f64>,
+    SpringTimeStep<f32>,
+    SpringTimeStep<f64>
+);
@@ -0,0 +1,16 @@
+use bevy_reflect::TypeRegistry;
+
+use crate::{Spring, SpringCollection, SpringConfig, SpringParams};
+
+/// Register the `f32` spring types with a Bevy [`TypeRegistry`], making them editable in
+/// reflection-based tools like `bevy-inspector-egui`.
+///
+/// Collections are registered for 2 and 3 dimensions. In a Bevy app, pass the registry from
+/// its `AppTypeRegistry` resource.
+pub fn register_spring_types(registry: &mut TypeRegistry) {
+    registry.register::<Spring<f32>>();
+    registry.register::<SpringConfig<f32>>();
+    registry.register::<SpringParams<f32>>();
+    registry.register::<SpringCollection<f32, 2>>();
+    registry.register::<SpringCollection<f32, 3>>();
+}